@@ -1,87 +1,261 @@
 use crate::{
-    error::Result,
-    idents::generate_idents,
-    input::{compare_used_names, try_parse_variants, validate_compare, VariantParsed},
+    docs::method_docs,
+    error::Error,
+    idents::{field_ident, field_method_name, generate_idents, mentions},
+    input::{
+        shadowed_trait_methods, try_parse_enum, try_parse_variants, validate_method_names,
+        validate_payload_struct, validate_selection, Codegen, EnumInput, MethodTable, RenameAll,
+        VariantParsed,
+    },
+    naming::Family,
+    payload::Payload,
 };
 
 use darling::ast::Style::{Struct, Tuple, Unit};
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote};
-use syn::{Ident, ItemEnum, Type};
+use quote::{format_ident, quote, ToTokens};
+use syn::{ext::IdentExt, Ident, Index, ItemEnum, Member, Type, Visibility};
 
-pub fn derive_variantly_fns(item_enum: ItemEnum) -> Result<TokenStream> {
+/// Derive the impl of an enum. Any errors are emitted alongside the methods that could still be derived.
+pub fn derive_variantly_fns(item_enum: ItemEnum) -> TokenStream {
     let enum_name = &item_enum.ident;
 
     // For collecting impl functions
     let mut functions = vec![];
+    // For collecting items declared alongside the impl block, such as payload structs.
+    let mut items = vec![];
+
+    // Parse & validate as much as possible, so that all errors are reported at once.
+    let (enum_input, mut errors) = try_parse_enum(&item_enum);
+    let (variants, variant_errors) = try_parse_variants(&item_enum);
+    errors.extend(variant_errors);
+    // Skipped variants are excluded from both validation & generation.
+    let variants: Vec<VariantParsed> = variants
+        .into_iter()
+        .map(|variant| VariantParsed::new(variant, &enum_input))
+        .filter(|variant| !variant.skip)
+        .collect();
 
-    let variants = try_parse_variants(&item_enum)?;
+    let naming = &enum_input.naming;
+    let table = MethodTable::new(&variants, naming);
+    errors.extend(table.validate(&variants, naming).err());
+
+    // Methods are still derived for every variant that validated cleanly, so that errors are reported alongside a
+    // best-effort impl rather than being buried beneath calls to methods that were never derived.
+    // Of two colliding variants, only the latter is left out.
+    let mut valid_variants: Vec<VariantParsed> = vec![];
+    let mut valid = vec![false; variants.len()];
+    variants
+        .into_iter()
+        .enumerate()
+        .for_each(|(index, variant)| {
+            let result = Error::collect(vec![
+                validate_method_names(&variant, naming),
+                validate_selection(&variant),
+                validate_payload_struct(&variant),
+            ]);
+            let collides = || table.collisions(index).iter().any(|other| valid[*other]);
+            match result {
+                Err(err) => errors.push(err),
+                Ok(_) if !collides() => {
+                    valid[index] = true;
+                    valid_variants.push(variant);
+                }
+                Ok(_) => (),
+            }
+        });
+    let variants = valid_variants;
 
-    validate_compare(&variants, vec![compare_used_names])?;
+    variants.iter().for_each(|variant| {
+        shadowed_trait_methods(variant, &enum_input.naming)
+            .into_iter()
+            .for_each(|(method, trait_path, receiver)| {
+                let method = method.unraw();
+                let shadowed_call = match receiver {
+                    true => String::from("when called with method syntax"),
+                    false => format!("when called through the path of the enum, such as `{}::{}(...)`", enum_name, method),
+                };
+                let message = format!(
+                    "the `{}` method derived for `{}::{}` shadows `{}::{}` {}. \
+                    use `#[variantly(rename = \"...\")]` to rename the variant's methods or `#[variantly(allow_shadowing)]` to allow this.",
+                    method, enum_name, variant.ident.unraw(), trait_path, method, shadowed_call
+                );
+                items.push(warning(&variant.ident, &message));
+            });
+    });
 
     variants.iter().for_each(|variant| {
         // This will be initialized with a tokenstream representing how to match & ignore any variables held by a variant.
 
         let ident = &variant.ident;
+        let vis = &variant.vis;
+        let constness = constness(&enum_input);
         let ignore = match &variant.fields.style {
             Tuple => {
-                handle_tuple(variant, &mut functions, enum_name);
+                handle_tuple(variant, &mut functions, &enum_input, None);
+                handle_fields(variant, &mut functions, &enum_input);
                 quote!((..))
             }
-            Struct => quote!({ .. }),
-            Unit => quote!(),
+            Struct => {
+                let payload = Payload::new(variant, &item_enum);
+                if let Some(payload) = &payload {
+                    items.push(payload.definitions(variant, &item_enum, &enum_input.codegen));
+                }
+                handle_tuple(variant, &mut functions, &enum_input, payload.as_ref());
+                handle_fields(variant, &mut functions, &enum_input);
+                quote!({ .. })
+            }
+            Unit => {
+                handle_unit(variant, &mut functions, &enum_input);
+                quote!()
+            }
         };
 
         // include any impl functions that are common to all variant types.
-        identify!(variant.used_name, [is, is_not, and, or]);
+        identify!(
+            enum_input.naming,
+            variant.used_name,
+            [is: Is, is_not: IsNot, and: And, or: Or]
+        );
+        push_selected(
+            variant,
+            &mut functions,
+            &enum_input,
+            None,
+            Family::COMMON,
+            vec![
+                (
+                    Family::Is,
+                    quote! {
+                        #vis #constness fn #is(&self) -> ::core::primitive::bool {
+                            match self {
+                                #enum_name::#ident#ignore => true,
+                                _ => false
+                            }
+                        }
+                    },
+                ),
+                (
+                    Family::IsNot,
+                    quote! {
+                        #vis #constness fn #is_not(&self) -> ::core::primitive::bool {
+                            match self {
+                                #enum_name::#ident#ignore => false,
+                                _ => true
+                            }
+                        }
+                    },
+                ),
+                (
+                    Family::And,
+                    quote! {
+                        #vis fn #and(self, and: Self) -> Self {
+                            match (&self, &and) {
+                                (#enum_name::#ident#ignore, #enum_name::#ident#ignore) => and,
+                                _ => self
+                            }
+                        }
+                    },
+                ),
+                (
+                    Family::Or,
+                    quote! {
+                        #vis fn #or(self, or: Self) -> Self {
+                            match &self {
+                                #enum_name::#ident#ignore => self,
+                                _ => or
+                            }
+                        }
+                    },
+                ),
+            ],
+        );
+    });
+
+    // Used by panic messages to name the variant that was actually found, including skipped variants.
+    if !item_enum.variants.is_empty() {
+        let variant_idents = item_enum.variants.iter().map(|variant| &variant.ident);
+        let variant_names = variant_idents
+            .clone()
+            .map(|ident| ident.unraw().to_string());
+        let variant_name_fn = variant_name_fn();
         functions.push(quote! {
-            pub fn #is(&self) -> bool {
+            #[allow(dead_code)]
+            fn #variant_name_fn(&self) -> &'static ::core::primitive::str {
                 match self {
-                    #enum_name::#ident#ignore => true,
-                    _ => false
+                    #( #enum_name::#variant_idents { .. } => #variant_names, )*
                 }
             }
+        });
+    }
 
-            pub fn #is_not(&self) -> bool {
-                !self.#is()
-            }
+    let (impl_generics, ty_generics, where_clause) = item_enum.generics.split_for_impl();
 
-            pub fn #and(self, and: Self) -> Self {
-                match (&self, &and) {
-                    (#enum_name::#ident#ignore, #enum_name::#ident#ignore) => and,
-                    _ => self
-                }
-            }
+    // Verbatim names are explicitly requested, so should not be linted against.
+    let allow_non_snake_case = match enum_input.rename_all {
+        RenameAll::Verbatim => quote!(#[allow(non_snake_case)]),
+        RenameAll::SnakeCase => quote!(),
+    };
 
-            pub fn #or(self, or: Self) -> Self {
-                match &self {
-                    #enum_name::#ident#ignore => self,
-                    _ => or
-                }
-            }
+    // Methods of private enums may go unused within the defining crate.
+    let allow_dead_code = match item_enum.vis {
+        Visibility::Inherited => quote!(#[allow(dead_code)]),
+        _ => quote!(),
+    };
 
+    let automatically_derived = enum_input
+        .codegen
+        .automatically_derived
+        .then(|| quote!(#[automatically_derived]));
+
+    // `variantly` only exports the derive itself, so derived code refers to no items through the `crate` path.
+    // It is still required to resolve, such that facade crates passing an invalid path are caught.
+    if let Some(krate) = &enum_input.krate {
+        items.push(quote! {
+            const _: () = {
+                #[allow(unused_imports)]
+                use #krate as _;
+            };
         });
-    });
-
-    let generics = &item_enum.generics;
-    let where_clause = &generics.where_clause;
+    }
 
     // Declare the actual impl block & iterate over all fns.
-    let output: TokenStream = quote! {
-        impl#generics #enum_name#generics #where_clause {
+    let output = quote! {
+        #allow_non_snake_case
+        #allow_dead_code
+        #automatically_derived
+        impl #impl_generics #enum_name #ty_generics #where_clause {
             #(#functions)*
         }
-    }
-    .into();
 
-    Ok(output)
+        #(#items)*
+    };
+
+    // Validation errors are emitted alongside the best-effort impl.
+    let errors = match errors.is_empty() {
+        true => TokenStream::new(),
+        false => Error::multiple(errors).into_compile_error(),
+    };
+
+    TokenStream::from(output)
+        .into_iter()
+        .chain(errors)
+        .collect()
 }
 
-/// Construct all impl functions related to variants with tuple style internal variables and add them to the functions vec.
-fn handle_tuple(variant: &VariantParsed, functions: &mut Vec<TokenStream2>, enum_name: &Ident) {
+/// Construct all impl functions related to variants with tuple or struct style internal variables and add them to the functions vec.
+/// Struct style variants expose their fields as a tuple in declaration order, or as the given payload structs.
+fn handle_tuple(
+    variant: &VariantParsed,
+    functions: &mut Vec<TokenStream2>,
+    enum_input: &EnumInput,
+    payload: Option<&Payload>,
+) {
     // parse necessary information from variant & fields.
+    let enum_name = &enum_input.ident;
     let ident = &variant.ident;
+    let vis = &variant.vis;
     let types: Vec<&Type> = variant
         .fields
         .fields
@@ -89,34 +263,72 @@ fn handle_tuple(variant: &VariantParsed, functions: &mut Vec<TokenStream2>, enum
         .map(|field| &field.ty)
         .collect();
 
-    // Generate a unique ident per type used in the variant
-    let vars = generate_idents(types.len());
-    let vars = quote! { (#( #vars ),*)};
+    let constness = constness(enum_input);
+
+    // Generate a stable binding per field of the variant
+    let vars = generate_idents(variant.fields.fields.iter().map(|field| &field.ident));
+
+    // used for both pattern matching and constructing variants:
+    // EX: var_pattern = SomeEnum::SomeVariant(some_variable_1, some_variable_2)
+    // EX: var_pattern = SomeEnum::SomeVariant { field_1: some_variable_1, field_2: some_variable_2 }
+    let var_pattern = match &variant.fields.style {
+        Struct => {
+            let names = variant.fields.fields.iter().map(|field| &field.ident);
+            quote! { #enum_name::#ident { #( #names: #vars ),* } }
+        }
+        _ => quote! { #enum_name::#ident(#( #vars ),*) },
+    };
 
-    let ref_types = quote! {(#( & #types ),*)};
-    let mut_types = quote! {(#( &mut #types ),*)};
-    let types = quote! { (#( #types ),*)};
+    // The values handed to & received from callers, along with their types.
+    // These are tuples of the variant values unless a payload struct was requested.
+    let (vars, ref_vars, mut_vars, types, ref_types, mut_types) = match payload {
+        Some(payload) => (
+            payload.value(&vars),
+            payload.ref_value(&vars),
+            payload.mut_value(&vars),
+            payload.ty(),
+            payload.ref_ty(),
+            payload.mut_ty(),
+        ),
+        None => {
+            let vars = quote! { (#( #vars ),*)};
+            (
+                vars.clone(),
+                vars.clone(),
+                vars,
+                quote! { (#( #types ),*)},
+                quote! {(#( & #types ),*)},
+                quote! {(#( &mut #types ),*)},
+            )
+        }
+    };
 
     // declare ident variables with helper macro.
     identify!(
+        enum_input.naming,
         variant.used_name,
         [
-            and_then,
-            expect,
-            ok_or_else,
-            ok_or,
-            ok,
-            or_else,
-            unwrap_or_else,
-            unwrap_or,
-            unwrap
+            and_then: AndThen,
+            expect: Expect,
+            ok_or_else: OkOrElse,
+            ok_or: OkOr,
+            ok: Ok,
+            or_else: OrElse,
+            unwrap_or_else: UnwrapOrElse,
+            unwrap_or: UnwrapOr,
+            unwrap: Unwrap,
+            var_fn: Value,
+            var_or_fn: ValueOr,
+            var_or_else_fn: ValueOrElse,
+            var_ref_fn: Ref,
+            var_ref_or_fn: RefOr,
+            var_ref_or_else_fn: RefOrElse,
+            var_mut_fn: Mut,
+            var_mut_or_fn: MutOr,
+            var_mut_or_else_fn: MutOrElse
         ]
     );
 
-    // used for both pattern matching and constructing variants:
-    // EX: var_pattern = SomeEnum::SomeVariant(some_variable_1, some_variable_2)
-    let var_pattern = quote! { #enum_name::#ident#vars };
-
     // Helper for deprecating methods
     let deprecate = |alternate| {
         let note = format!(
@@ -131,130 +343,565 @@ fn handle_tuple(variant: &VariantParsed, functions: &mut Vec<TokenStream2>, enum
         }
     };
 
-    let var_fn = &variant.used_name;
-    let var_or_fn = format_ident!("{}_or", var_fn);
-    let var_or_else_fn = format_ident!("{}_or_else", var_fn);
-
-    let var_ref_fn = format_ident!("{}_ref", var_fn);
-    let var_ref_or_fn = format_ident!("{}_ref_or", var_fn);
-    let var_ref_or_else_fn = format_ident!("{}_ref_or_else", var_fn);
-
-    let var_mut_fn = format_ident!("{}_mut", var_fn);
-    let var_mut_or_fn = format_ident!("{}_mut_or", var_fn);
-    let var_mut_or_else_fn = format_ident!("{}_mut_or_else", var_fn);
-
-    let ok_deprecation = deprecate(var_fn);
+    let ok_deprecation = deprecate(&var_fn);
     let ok_or_deprecation = deprecate(&var_or_fn);
     let ok_or_else_deprecation = deprecate(&var_or_else_fn);
 
-    // Create and push actual impl functions
-    functions.push(quote! {
-        pub fn #var_fn(self) -> std::option::Option<(#types)> {
-            match self {
-                #var_pattern => std::option::Option::Some((#vars)),
-                _ => std::option::Option::None,
-            }
-        }
-
-        pub fn #var_ref_fn(&self) -> std::option::Option<(#ref_types)> {
-            match self {
-                #var_pattern => std::option::Option::Some((#vars)),
-                _ => std::option::Option::None,
-            }
-        }
-
-        pub fn #var_mut_fn(&mut self) -> std::option::Option<(#mut_types)> {
-            match self {
-                #var_pattern => std::option::Option::Some((#vars)),
-                _ => std::option::Option::None,
-            }
-        }
-
-        pub fn #var_or_fn<E>(self, or: E) -> std::result::Result<(#types), E> {
-            self.#var_or_else_fn(|| or)
-        }
+    let (expect_panic, unwrap_panic) = panics(enum_input, &unwrap);
+    let (e, f) = method_generics(enum_input, variant);
+
+    // Create and push actual impl functions. Each is self-contained, as any other family may be deselected.
+    push_selected(
+        variant,
+        functions,
+        enum_input,
+        None,
+        Family::VALUE,
+        vec![
+            (
+                Family::Value,
+                quote! {
+                    #vis fn #var_fn(self) -> ::core::option::Option<(#types)> {
+                        match self {
+                            #var_pattern => ::core::option::Option::Some((#vars)),
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                },
+            ),
+            (
+                Family::Ref,
+                quote! {
+                    #vis #constness fn #var_ref_fn(&self) -> ::core::option::Option<(#ref_types)> {
+                        match self {
+                            #var_pattern => ::core::option::Option::Some((#ref_vars)),
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                },
+            ),
+            (
+                Family::Mut,
+                quote! {
+                    #vis fn #var_mut_fn(&mut self) -> ::core::option::Option<(#mut_types)> {
+                        match self {
+                            #var_pattern => ::core::option::Option::Some((#mut_vars)),
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                },
+            ),
+            (
+                Family::ValueOr,
+                quote! {
+                    #vis fn #var_or_fn<#e>(self, or: #e) -> ::core::result::Result<(#types), #e> {
+                        match self {
+                            #var_pattern => ::core::result::Result::Ok((#vars)),
+                            _ => ::core::result::Result::Err(or)
+                        }
+                    }
+                },
+            ),
+            (
+                Family::ValueOrElse,
+                quote! {
+                    #vis fn #var_or_else_fn<#e, #f: ::core::ops::FnOnce() -> #e>(self, or_else: #f) -> ::core::result::Result<(#types), #e> {
+                        match self {
+                            #var_pattern => ::core::result::Result::Ok((#vars)),
+                            _ => ::core::result::Result::Err(or_else())
+                        }
+                    }
+                },
+            ),
+            (
+                Family::RefOr,
+                quote! {
+                    #vis fn #var_ref_or_fn<#e>(&self, or: #e) -> ::core::result::Result<(#ref_types), #e> {
+                        match self {
+                            #var_pattern => ::core::result::Result::Ok((#ref_vars)),
+                            _ => ::core::result::Result::Err(or)
+                        }
+                    }
+                },
+            ),
+            (
+                Family::MutOr,
+                quote! {
+                    #vis fn #var_mut_or_fn<#e>(&mut self, or: #e) -> ::core::result::Result<(#mut_types), #e> {
+                        match self {
+                            #var_pattern => ::core::result::Result::Ok((#mut_vars)),
+                            _ => ::core::result::Result::Err(or)
+                        }
+                    }
+                },
+            ),
+            (
+                Family::RefOrElse,
+                quote! {
+                    #vis fn #var_ref_or_else_fn<#e, #f: ::core::ops::FnOnce() -> #e>(&self, or_else: #f) -> ::core::result::Result<(#ref_types), #e> {
+                        match self {
+                            #var_pattern => ::core::result::Result::Ok((#ref_vars)),
+                            _ => ::core::result::Result::Err(or_else())
+                        }
+                    }
+                },
+            ),
+            (
+                Family::MutOrElse,
+                quote! {
+                    #vis fn #var_mut_or_else_fn<#e, #f: ::core::ops::FnOnce() -> #e>(&mut self, or_else: #f) -> ::core::result::Result<(#mut_types), #e> {
+                        match self {
+                            #var_pattern => ::core::result::Result::Ok((#mut_vars)),
+                            _ => ::core::result::Result::Err(or_else())
+                        }
+                    }
+                },
+            ),
+            (
+                Family::AndThen,
+                quote! {
+                    #vis fn #and_then<#f: ::core::ops::FnOnce((#types)) -> (#types)>(self, and_then: #f) -> Self {
+                        match self {
+                            #var_pattern => {
+                                let #vars = and_then(#vars);
+                                #var_pattern
+                            },
+                            _ => self
+                        }
+                    }
+                },
+            ),
+            (
+                Family::Expect,
+                quote! {
+                    #vis fn #expect(self, msg: &::core::primitive::str) -> (#types) {
+                        match self {
+                            #var_pattern => (#vars),
+                            _ => #expect_panic,
+                        }
+                    }
+                },
+            ),
+            (
+                Family::Ok,
+                quote! {
+                    #ok_deprecation
+                    #vis fn #ok(self) -> ::core::option::Option<(#types)> {
+                        match self {
+                            #var_pattern => ::core::option::Option::Some((#vars)),
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                },
+            ),
+            (
+                Family::OkOr,
+                quote! {
+                    #ok_or_deprecation
+                    #vis fn #ok_or<#e>(self, or: #e) -> ::core::result::Result<(#types), #e> {
+                        match self {
+                            #var_pattern => ::core::result::Result::Ok((#vars)),
+                            _ => ::core::result::Result::Err(or)
+                        }
+                    }
+                },
+            ),
+            (
+                Family::OkOrElse,
+                quote! {
+                    #ok_or_else_deprecation
+                    #vis fn #ok_or_else<#e, #f: ::core::ops::FnOnce() -> #e>(self, or_else: #f) -> ::core::result::Result<(#types), #e> {
+                        match self {
+                            #var_pattern => ::core::result::Result::Ok((#vars)),
+                            _ => ::core::result::Result::Err(or_else())
+                        }
+                    }
+                },
+            ),
+            (
+                Family::OrElse,
+                quote! {
+                    #vis fn #or_else<#f: ::core::ops::FnOnce() -> (#types)>(self, or_else: #f) -> Self {
+                        match self {
+                            #var_pattern => #var_pattern,
+                            _ => {
+                                let #vars = or_else();
+                                #var_pattern
+                            }
+                        }
+                    }
+                },
+            ),
+            (
+                Family::Unwrap,
+                quote! {
+                    #vis fn #unwrap(self) -> (#types) {
+                        match self {
+                            #var_pattern => (#vars),
+                            _ => #unwrap_panic,
+                        }
+                    }
+                },
+            ),
+            (
+                Family::UnwrapOr,
+                quote! {
+                    #vis fn #unwrap_or(self, or: (#types)) -> (#types) {
+                        match self {
+                            #var_pattern => (#vars),
+                            _ => or
+                        }
+                    }
+                },
+            ),
+            (
+                Family::UnwrapOrElse,
+                quote! {
+                    #vis fn #unwrap_or_else<#f: ::core::ops::FnOnce() -> (#types)>(self, or_else: #f) -> (#types) {
+                        match self {
+                            #var_pattern => (#vars),
+                            _ => or_else()
+                        }
+                    }
+                },
+            ),
+        ],
+    );
+}
 
-        pub fn #var_or_else_fn<E, F: std::ops::FnOnce() -> E>(self, or_else: F) -> std::result::Result<(#types), E> {
-            match self {
-                #var_pattern => std::result::Result::Ok((#vars)),
-                _ => std::result::Result::Err(or_else())
-            }
-        }
+/// Construct all impl functions related to unit variants and add them to the functions vec.
+/// These mirror the tuple style functions, with `()` in place of the inner values.
+fn handle_unit(variant: &VariantParsed, functions: &mut Vec<TokenStream2>, enum_input: &EnumInput) {
+    let enum_name = &enum_input.ident;
+    let ident = &variant.ident;
+    let vis = &variant.vis;
 
-        pub fn #var_ref_or_fn<E>(&self, or: E) -> std::result::Result<(#ref_types), E> {
-            self.#var_ref_or_else_fn(|| or)
-        }
+    identify!(
+        enum_input.naming,
+        variant.used_name,
+        [
+            expect: Expect,
+            unwrap: Unwrap,
+            var_or_fn: ValueOr,
+            var_or_else_fn: ValueOrElse
+        ]
+    );
 
-        pub fn #var_mut_or_fn<E>(&mut self, or: E) -> std::result::Result<(#mut_types), E> {
-            self.#var_mut_or_else_fn(|| or)
-        }
+    let (expect_panic, unwrap_panic) = panics(enum_input, &unwrap);
+    let (e, f) = method_generics(enum_input, variant);
+
+    push_selected(
+        variant,
+        functions,
+        enum_input,
+        None,
+        Family::UNIT,
+        vec![
+            (
+                Family::ValueOr,
+                quote! {
+                    #vis fn #var_or_fn<#e>(self, or: #e) -> ::core::result::Result<(), #e> {
+                        match self {
+                            #enum_name::#ident => ::core::result::Result::Ok(()),
+                            _ => ::core::result::Result::Err(or)
+                        }
+                    }
+                },
+            ),
+            (
+                Family::ValueOrElse,
+                quote! {
+                    #vis fn #var_or_else_fn<#e, #f: ::core::ops::FnOnce() -> #e>(self, or_else: #f) -> ::core::result::Result<(), #e> {
+                        match self {
+                            #enum_name::#ident => ::core::result::Result::Ok(()),
+                            _ => ::core::result::Result::Err(or_else())
+                        }
+                    }
+                },
+            ),
+            (
+                Family::Expect,
+                quote! {
+                    #vis fn #expect(self, msg: &::core::primitive::str) {
+                        match self {
+                            #enum_name::#ident => (),
+                            _ => #expect_panic,
+                        }
+                    }
+                },
+            ),
+            (
+                Family::Unwrap,
+                quote! {
+                    #vis fn #unwrap(self) {
+                        match self {
+                            #enum_name::#ident => (),
+                            _ => #unwrap_panic,
+                        }
+                    }
+                },
+            ),
+        ],
+    );
+}
 
-        pub fn #var_ref_or_else_fn<E, F: std::ops::FnOnce() -> E>(&self, or_else: F) -> std::result::Result<(#ref_types), E> {
-            match self {
-                #var_pattern => std::result::Result::Ok((#vars)),
-                _ => std::result::Result::Err(or_else())
-            }
-        }
+/// Construct accessors & updaters for each individual field of a tuple or struct style variant and add them to the functions vec.
+/// Named fields are referred to by name, EX: `{variant_name}_{field_name}_ref`, while tuple fields are referred to by
+/// position, EX: `{variant_name}_0_ref`.
+fn handle_fields(
+    variant: &VariantParsed,
+    functions: &mut Vec<TokenStream2>,
+    enum_input: &EnumInput,
+) {
+    let enum_name = &enum_input.ident;
+    let ident = &variant.ident;
+    let vis = &variant.vis;
+    let var = field_ident("value");
+    let constness = constness(enum_input);
 
-        pub fn #var_mut_or_else_fn<E, F: std::ops::FnOnce() -> E>(&mut self, or_else: F) -> std::result::Result<(#mut_types), E> {
-            match self {
-                #var_pattern => std::result::Result::Ok((#vars)),
-                _ => std::result::Result::Err(or_else())
-            }
-        }
+    variant
+        .fields
+        .fields
+        .iter()
+        .zip(variant.field_names())
+        .enumerate()
+        .for_each(|(index, (field, field_name))| {
+            let ty = &field.ty;
+            let member = match &field.ident {
+                Some(field_ident) => Member::Named(field_ident.clone()),
+                None => Member::Unnamed(Index::from(index)),
+            };
+
+            // Bind only the given field, which works for both tuple & struct style variants.
+            // EX: var_pattern = SomeEnum::SomeVariant { 1: some_variable, .. }
+            let var_pattern = quote! { #enum_name::#ident { #member: #var, .. } };
+
+            let name = field_method_name(&variant.used_name, &field_name);
+            identify!(
+                enum_input.naming,
+                name,
+                [
+                    ref_fn: FieldRef,
+                    mut_fn: FieldMut,
+                    with_fn: With,
+                    set_fn: Set
+                ]
+            );
+
+            push_selected(
+                variant,
+                functions,
+                enum_input,
+                Some((&name, &field_name)),
+                Family::FIELD,
+                vec![
+                    (
+                        Family::FieldRef,
+                        quote! {
+                            #vis #constness fn #ref_fn(&self) -> ::core::option::Option<&#ty> {
+                                match self {
+                                    #var_pattern => ::core::option::Option::Some(#var),
+                                    _ => ::core::option::Option::None,
+                                }
+                            }
+                        },
+                    ),
+                    (
+                        Family::FieldMut,
+                        quote! {
+                            #vis fn #mut_fn(&mut self) -> ::core::option::Option<&mut #ty> {
+                                match self {
+                                    #var_pattern => ::core::option::Option::Some(#var),
+                                    _ => ::core::option::Option::None,
+                                }
+                            }
+                        },
+                    ),
+                    (
+                        Family::With,
+                        quote! {
+                            #vis fn #with_fn(mut self, value: #ty) -> Self {
+                                if let #var_pattern = &mut self {
+                                    *#var = value;
+                                }
+                                self
+                            }
+                        },
+                    ),
+                    (
+                        Family::Set,
+                        quote! {
+                            #vis fn #set_fn(&mut self, value: #ty) -> ::core::option::Option<#ty> {
+                                match self {
+                                    #var_pattern => ::core::option::Option::Some(::core::mem::replace(#var, value)),
+                                    _ => ::core::option::Option::None,
+                                }
+                            }
+                        },
+                    ),
+                ],
+            );
+        });
+}
 
-        pub fn #and_then<F: std::ops::FnOnce((#types)) -> (#types)>(self, and_then: F) -> Self {
-            match self {
-                #var_pattern => {
-                    let #vars = and_then(#vars);
-                    #var_pattern
-                },
-                _ => self
-            }
-        }
+/// The `const` qualifier for methods that may be declared as `const fn`, unless opted out of through
+/// `#[variantly(const_fn = false)]`. Only methods that neither drop nor mutate values qualify.
+fn constness(enum_input: &EnumInput) -> TokenStream2 {
+    if enum_input.const_fn {
+        quote!(const)
+    } else {
+        quote!()
+    }
+}
 
-        pub fn #expect(self, msg: &str) -> (#types) {
-            self.#unwrap_or_else(|| std::panic!("{}", msg))
-        }
+/// Add the methods of each family selected for the given variant to the functions vec.
+/// The given methods must be exactly those of the given family list, against which derived names are validated.
+/// Each method is preceded by its docs & the codegen attributes enabled for its family. Methods concerning a single
+/// field are given the name used for naming the field along with the name of the field itself.
+fn push_selected(
+    variant: &VariantParsed,
+    functions: &mut Vec<TokenStream2>,
+    enum_input: &EnumInput,
+    field: Option<(&Ident, &str)>,
+    families: &[Family],
+    methods: Vec<(Family, TokenStream2)>,
+) {
+    // A method missing from its family list would escape validation of its name.
+    let derived: Vec<Family> = methods.iter().map(|(family, _)| *family).collect();
+    assert!(
+        derived.len() == families.len() && families.iter().all(|family| derived.contains(family)),
+        "derived families {:?} do not match the listed families {:?}",
+        derived,
+        families
+    );
 
-        #ok_deprecation
-        pub fn #ok(self) -> std::option::Option<(#types)> {
-            self.#var_fn()
-        }
+    functions.extend(
+        methods
+            .into_iter()
+            .filter(|(family, _)| variant.includes(*family))
+            .map(|(family, method)| {
+                let name = field.map_or(&variant.used_name, |(name, _)| name);
+                let docs = method_docs(
+                    enum_input,
+                    variant,
+                    family,
+                    field.map(|(_, field_name)| field_name),
+                    &enum_input.naming.method(family, name),
+                );
+                let attrs = codegen_attrs(&enum_input.codegen, family);
+                quote! { #docs #attrs #method }
+            }),
+    );
+}
 
-        #ok_or_deprecation
-        pub fn #ok_or<E>(self, or: E) -> std::result::Result<(#types), E> {
-            self.#var_or_fn(or)
-        }
+/// The codegen attributes enabled for methods of the given family.
+fn codegen_attrs(codegen: &Codegen, family: Family) -> TokenStream2 {
+    use Family::*;
+
+    let inline = codegen.inline.then(|| quote!(#[inline]));
+    // Results are already `#[must_use]`, as are the methods derived for them.
+    let must_use = (codegen.must_use
+        && matches!(
+            family,
+            Is | IsNot
+                | And
+                | Or
+                | AndThen
+                | OrElse
+                | Value
+                | Ref
+                | Mut
+                | Ok
+                | FieldRef
+                | FieldMut
+                | With
+        ))
+    .then(|| quote!(#[must_use]));
+    let track_caller = (codegen.track_caller && matches!(family, Expect | Unwrap))
+        .then(|| quote!(#[track_caller]));
+
+    quote! { #inline #must_use #track_caller }
+}
 
-        #ok_or_else_deprecation
-        pub fn #ok_or_else<E, F: std::ops::FnOnce() -> E>(self, or_else: F) -> std::result::Result<(#types), E> {
-            self.#var_or_else_fn(or_else)
-        }
+/// Emit a compile time warning pointing at the given ident, by way of using a deprecated item.
+fn warning(ident: &Ident, message: &str) -> TokenStream2 {
+    let warning = Ident::new("variantly_warning", ident.span());
+    quote! {
+        const _: () = {
+            #[deprecated(note = #message)]
+            #[allow(non_upper_case_globals)]
+            const variantly_warning: () = ();
+            let _ = #warning;
+        };
+    }
+}
 
-        pub fn #or_else<F: std::ops::FnOnce() -> (#types)>(self, or_else: F) -> Self {
-            match self {
-                #var_pattern => #var_pattern,
-                _ => {
-                    let #vars = or_else();
-                    #var_pattern
-                }
-            }
+/// Names for the generic error & closure parameters of derived methods, which must not collide with the generic
+/// parameters of the enum nor any type named within the fields of the variant, as type parameters are not hygienic.
+/// EX: `E` & `F`, or `E_` for `enum Outcome<E> { Failed(E) }` & `enum Foo { A(E) }` alike.
+fn method_generics(enum_input: &EnumInput, variant: &VariantParsed) -> (Ident, Ident) {
+    let generics = &enum_input.generics;
+    let types = variant
+        .fields
+        .fields
+        .iter()
+        .map(|field| field.ty.to_token_stream());
+    let used: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .chain(generics.const_params().map(|param| param.ident.clone()))
+        .collect();
+    let fresh = |name: &str| {
+        let mut name = format_ident!("{}", name);
+        while used.contains(&name) || types.clone().any(|ty| mentions(ty, &[name.clone()])) {
+            name = format_ident!("{}_", name);
         }
+        name
+    };
+    (fresh("E"), fresh("F"))
+}
 
-        pub fn #unwrap(self) -> (#types) {
-            self.#unwrap_or_else(|| std::panic!())
-        }
+/// Name of the private method for looking up the name of the current variant.
+fn variant_name_fn() -> Ident {
+    format_ident!("__variantly_variant_name")
+}
 
-        pub fn #unwrap_or(self, or: (#types)) -> (#types) {
-            self.#unwrap_or_else(|| or)
-        }
+/// The panic paths of the `expect` & `unwrap` methods of a variant, which name the variant that was actually found.
+/// EX: "called `Color::unwrap_rgb()` on a `Color::Grey` value"
+fn panics(enum_input: &EnumInput, unwrap: &Ident) -> (TokenStream2, TokenStream2) {
+    let enum_name = &enum_input.ident;
+    let variant_name_fn = variant_name_fn();
+    let expect_message = format!("{{}}: found a `{}::{{}}` value", enum_name);
+    let unwrap_message = format!(
+        "called `{}::{}()` on a `{}::{{}}` value",
+        enum_name, unwrap, enum_name
+    );
+    (
+        panic(
+            &enum_input.codegen,
+            quote!(#expect_message, msg, self.#variant_name_fn()),
+        ),
+        panic(
+            &enum_input.codegen,
+            quote!(#unwrap_message, self.#variant_name_fn()),
+        ),
+    )
+}
 
-        pub fn #unwrap_or_else<F: std::ops::FnOnce() -> (#types)>(self, or_else: F) -> (#types) {
-            match self {
-                #var_pattern => (#vars),
-                _ => or_else()
+/// Panic with the given `core::panic!` arguments, from within a `#[cold]` function when enabled.
+fn panic(codegen: &Codegen, args: TokenStream2) -> TokenStream2 {
+    if codegen.cold {
+        let track_caller = codegen.track_caller.then(|| quote!(#[track_caller]));
+        quote! {{
+            #[cold]
+            #[inline(never)]
+            #track_caller
+            fn cold_panic(args: ::core::fmt::Arguments) -> ! {
+                ::core::panic!("{}", args)
             }
-        }
-    });
+            cold_panic(::core::format_args!(#args))
+        }}
+    } else {
+        quote! { ::core::panic!(#args) }
+    }
 }
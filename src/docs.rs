@@ -0,0 +1,86 @@
+//! Generate rustdoc for derived methods.
+use crate::{
+    input::{EnumInput, VariantParsed},
+    naming::Family,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{ext::IdentExt, Ident};
+
+/// Describe the semantics of each family, where `{}` is replaced by a link to the variant or a description of the field.
+fn description(family: Family) -> &'static str {
+    use Family::*;
+
+    match family {
+        Is => "Returns `true` if the enum is {}.",
+        IsNot => "Returns `true` if the enum is not {}.",
+        And => "Returns `and` if both `self` & `and` are {}, otherwise returns `self`.",
+        Or => "Returns `self` if it is {}, otherwise returns `or`.",
+        AndThen => "Replaces the contents of {} with the result of calling `and_then` with them, otherwise returns `self` unchanged.",
+        OrElse => "Returns `self` if it is {}, otherwise constructs one from the result of `or_else`.",
+        Expect => "Returns the contents of {}.\n\n# Panics\nPanics with the message `msg` if the enum is not {}.",
+        Unwrap => "Returns the contents of {}.\n\n# Panics\nPanics if the enum is not {}.",
+        UnwrapOr => "Returns the contents of {}, otherwise returns `or`.",
+        UnwrapOrElse => "Returns the contents of {}, otherwise returns the result of `or_else`.",
+        Value | Ok => "Returns the contents of {} as `Some`, otherwise returns `None`.",
+        ValueOr | OkOr => "Returns the contents of {} as `Ok`, otherwise returns `Err(or)`.",
+        ValueOrElse | OkOrElse => "Returns the contents of {} as `Ok`, otherwise returns `Err` with the result of `or_else`.",
+        Ref => "Returns references to the contents of {} as `Some`, otherwise returns `None`.",
+        RefOr => "Returns references to the contents of {} as `Ok`, otherwise returns `Err(or)`.",
+        RefOrElse => "Returns references to the contents of {} as `Ok`, otherwise returns `Err` with the result of `or_else`.",
+        Mut => "Returns mutable references to the contents of {} as `Some`, otherwise returns `None`.",
+        MutOr => "Returns mutable references to the contents of {} as `Ok`, otherwise returns `Err(or)`.",
+        MutOrElse => "Returns mutable references to the contents of {} as `Ok`, otherwise returns `Err` with the result of `or_else`.",
+        FieldRef => "Returns a reference to {} as `Some`, otherwise returns `None`.",
+        FieldMut => "Returns a mutable reference to {} as `Some`, otherwise returns `None`.",
+        With => "Replaces {} with `value`, returning the enum.",
+        Set => "Replaces {} with `value`, returning the previous value as `Some`, otherwise returns `None` leaving the enum unchanged.",
+    }
+}
+
+/// Document a derived method of the given family. The variant's own docs follow the summary of the method, and the
+/// variant name is registered as a `doc(alias)` so that searching for the variant finds its methods.
+pub fn method_docs(
+    enum_input: &EnumInput,
+    variant: &VariantParsed,
+    family: Family,
+    field: Option<&str>,
+    method: &Ident,
+) -> TokenStream2 {
+    let variant_name = variant.ident.unraw().to_string();
+    let link = format!(
+        "a [`{}::{}`](Self::{}) value",
+        enum_input.ident, variant_name, variant.ident
+    );
+    let subject = match field {
+        Some(field) => format!("the `{}` field of {}", field, link),
+        None => link,
+    };
+
+    let description = description(family).replace("{}", &subject);
+    let mut paragraphs = description.splitn(2, "\n\n");
+    let summary = paragraphs.next().unwrap_or_default();
+    let details = paragraphs.next().map(|details| {
+        let lines = details.lines();
+        quote! {
+            #[doc = ""]
+            #( #[doc = #lines] )*
+        }
+    });
+    let variant_docs = (!variant.docs.is_empty()).then(|| {
+        let docs = &variant.docs;
+        quote! {
+            #[doc = ""]
+            #( #docs )*
+        }
+    });
+    // Rustdoc rejects aliases that are identical to the name of the item.
+    let alias = (method.unraw() != variant_name).then(|| quote!(#[doc(alias = #variant_name)]));
+
+    quote! {
+        #[doc = #summary]
+        #variant_docs
+        #details
+        #alias
+    }
+}
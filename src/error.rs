@@ -8,6 +8,8 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     Syn(SynError),
     Darling(DarlingError),
+    /// Several errors that are reported at once.
+    Multiple(Vec<Error>),
 }
 
 impl Error {
@@ -15,6 +17,32 @@ impl Error {
         match self {
             Error::Syn(err) => err.to_compile_error().into(),
             Error::Darling(err) => err.write_errors().into(),
+            Error::Multiple(errors) => errors.into_iter().map(Error::into_compile_error).collect(),
+        }
+    }
+
+    /// Collect the values of all given results, or every error should any fail.
+    pub fn collect<T, I: IntoIterator<Item = Result<T>>>(results: I) -> Result<Vec<T>> {
+        let mut values = vec![];
+        let mut errors = vec![];
+        results.into_iter().for_each(|result| match result {
+            Ok(value) => values.push(value),
+            Err(err) => errors.push(err),
+        });
+
+        if errors.is_empty() {
+            Ok(values)
+        } else {
+            Err(Error::multiple(errors))
+        }
+    }
+
+    /// Combine the given errors into one, which must not be empty.
+    pub fn multiple(mut errors: Vec<Error>) -> Error {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            Error::Multiple(errors)
         }
     }
 }
@@ -30,3 +58,15 @@ impl From<SynError> for Error {
         Error::Syn(err)
     }
 }
+
+/// Suggest the candidate most similar to the given unknown name, should any be similar enough.
+/// EX: " Did you mean `rename`?"
+pub fn did_you_mean<'a, I: IntoIterator<Item = &'a str>>(name: &str, candidates: I) -> String {
+    candidates
+        .into_iter()
+        .map(|candidate| (strsim::jaro_winkler(name, candidate), candidate))
+        .filter(|(similarity, _)| *similarity > 0.8)
+        .max_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, candidate)| format!(" Did you mean `{}`?", candidate))
+        .unwrap_or_default()
+}
@@ -1,44 +1,141 @@
 //! Parse or generate idents.
+use inflector::cases::snakecase::to_snake_case;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
 use quote::format_ident;
 use syn::Ident;
-use uuid::Uuid;
 
-/// Declare a series of vars named by `operation` that contain an ident created
-/// by concatenating the stringified `operation`, and the passed in `ident`.
+/// Declare a series of vars named by `operation` that contain the ident of the derived method of the
+/// given `Family`, named by `naming` for the passed in `ident`.
 /// # Examples
 /// ```ignore
 /// # use quote::format_ident;
 /// let foo = format_ident!("{}", "foo");
-/// identify!(foo, [get, and]);
+/// identify!(naming, foo, [is: Is, and: And]);
 /// // Expands to:
-/// let get = format_ident!("{}_{}", stringify!(get), to_snake_case(&foo.to_string()));
-/// let and = format_ident!("{}_{}", stringify!(and), to_snake_case(&foo.to_string()));
-/// // Which results in:
-/// assert_eq!(get.to_string(), "get_foo");
+/// let is = naming.method(Family::Is, &foo);
+/// let and = naming.method(Family::And, &foo);
+/// // Which, with the default naming, results in:
+/// assert_eq!(is.to_string(), "is_foo");
 /// assert_eq!(and.to_string(), "and_foo");
 /// ```
 macro_rules! identify {
-    ($ident:expr, [$($operation:ident$(,)*)*]) => {
+    ($naming:expr, $ident:expr, [$($operation:ident: $family:ident$(,)*)*]) => {
         $(
-            let $operation = format_ident!(
-                "{}_{}",
-                stringify!($operation),
-                $ident
-            );
+            let $operation = $naming.method(crate::naming::Family::$family, &$ident);
         )*
     };
 }
 
-/// Generate the given number of unique and random idents and collect them into a vec.
-pub fn generate_idents(count: usize) -> Vec<Ident> {
-    let mut idents: Vec<Ident> = vec![];
-    for _ in 0..count {
-        idents.push(unique_ident())
+/// Generate a stable binding for each of the given fields, named after the field or its position.
+/// Bindings are hygienic, so they can not collide with any other names in scope.
+/// EX: `name`, `alpha` for `Custom { name: String, alpha: u8 }`, or `field_0`, `field_1` for `Grey(u8, u8)`
+pub fn generate_idents<'a, I: IntoIterator<Item = &'a Option<Ident>>>(fields: I) -> Vec<Ident> {
+    fields
+        .into_iter()
+        .enumerate()
+        .map(|(index, ident)| match ident {
+            Some(ident) => {
+                let mut ident = ident.clone();
+                ident.set_span(Span::mixed_site());
+                ident
+            }
+            None => field_ident(&index.to_string()),
+        })
+        .collect()
+}
+
+/// Generate a stable & hygienic binding for a single field.
+pub fn field_ident(name: &str) -> Ident {
+    format_ident!("field_{}", name, span = Span::mixed_site())
+}
+
+/// Join the name of a variant with the name of one of its fields, for naming methods concerning a single field.
+/// Leading & trailing underscores of the field name are dropped, so that the joined name remains snake_case.
+/// EX: `point_x` for `x` of `Point`, or `point_self` for `self_` of `Point`
+pub fn field_method_name(used_name: &Ident, field_name: &str) -> Ident {
+    let trimmed = field_name.trim_matches('_');
+    let field_name = if trimmed.is_empty() {
+        field_name
+    } else {
+        trimmed
+    };
+    format_ident!("{}_{}", used_name, field_name)
+}
+
+/// Coerce the given name into snake_case, treating each of the given acronyms as a single word.
+/// # Examples
+/// ```ignore
+/// let acronyms = vec![String::from("IPv4"), String::from("HTTP")];
+/// assert_eq!(to_snake_case_with_acronyms("IPv4Addr", &acronyms), "ipv4_addr");
+/// assert_eq!(to_snake_case_with_acronyms("MyHTTPServer", &acronyms), "my_http_server");
+/// ```
+pub fn to_snake_case_with_acronyms(name: &str, acronyms: &[String]) -> String {
+    let mut words = vec![];
+    let mut rest = name;
+    let mut segment = String::new();
+
+    while let Some(next) = rest.chars().next() {
+        // An acronym is only matched when it is not followed by the lowercase remainder of a word.
+        let acronym = acronyms.iter().find(|acronym| {
+            !acronym.is_empty()
+                && rest.starts_with(acronym.as_str())
+                && !rest[acronym.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(char::is_lowercase)
+        });
+
+        match acronym {
+            Some(acronym) => {
+                words.push(segment_to_snake_case(&segment));
+                words.push(acronym.to_lowercase());
+                segment.clear();
+                rest = &rest[acronym.len()..];
+            }
+            None => {
+                segment.push(next);
+                rest = &rest[next.len_utf8()..];
+            }
+        }
+    }
+    words.push(segment_to_snake_case(&segment));
+
+    words
+        .into_iter()
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Coerce a segment of a name into snake_case. Inflector treats every non-ASCII character as the start of a new word,
+/// so names containing any are instead split at each change in case.
+fn segment_to_snake_case(segment: &str) -> String {
+    if segment.is_ascii() {
+        return to_snake_case(segment);
     }
-    idents
+
+    let chars: Vec<char> = segment.chars().collect();
+    let mut snake = String::new();
+    chars.iter().enumerate().for_each(|(index, &c)| {
+        let starts_word = c.is_uppercase()
+            && index > 0
+            && (!chars[index - 1].is_uppercase()
+                || chars.get(index + 1).is_some_and(|next| next.is_lowercase()));
+        if (starts_word || c == '_') && !snake.is_empty() && !snake.ends_with('_') {
+            snake.push('_');
+        }
+        if c != '_' {
+            snake.extend(c.to_lowercase());
+        }
+    });
+    snake
 }
 
-/// Generate a valid, unique and random ident.
-pub fn unique_ident() -> Ident {
-    format_ident!("ident_{}", Uuid::new_v4().to_simple().to_string())
+/// Check whether any of the given idents occur within the tokens.
+pub fn mentions(tokens: TokenStream2, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => mentions(group.stream(), idents),
+        _ => false,
+    })
 }
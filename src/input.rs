@@ -1,16 +1,167 @@
-use crate::error::Result;
-use darling::{ast::Fields, FromVariant};
-use inflector::cases::snakecase::to_snake_case;
+use crate::{
+    error::{did_you_mean, Error, Result},
+    idents::{field_method_name, to_snake_case_with_acronyms},
+    naming::{self, Family, FamilyList, Naming, Selection},
+};
+use darling::{
+    ast::{Fields, Style},
+    util::Override,
+    FromDeriveInput, FromMeta, FromVariant,
+};
 use quote::format_ident;
-use syn::{Attribute, Ident, ItemEnum, Type, Visibility};
+use std::collections::HashMap;
+use syn::{
+    ext::IdentExt, Attribute, DeriveInput, Generics, Ident, ItemEnum, Lit, Meta, NestedMeta, Path,
+    Type, Variant, Visibility,
+};
+
+/// Struct for parsing relevant input to a variantly derived enum.
+#[derive(FromDeriveInput, Debug)]
+#[darling(attributes(variantly), supports(enum_any))]
+pub struct EnumInput {
+    pub ident: Ident,
+    pub vis: Visibility,
+    pub generics: Generics,
+    #[darling(default)]
+    pub rename_all: RenameAll,
+    #[darling(default)]
+    pub acronyms: StringList,
+    #[darling(default)]
+    pub naming: Naming,
+    #[darling(default)]
+    pub style: naming::Style,
+    #[darling(default)]
+    pub only: Option<FamilyList>,
+    #[darling(default)]
+    pub exclude: Option<FamilyList>,
+    #[darling(default, rename = "vis")]
+    pub method_vis: Option<Vis>,
+    #[darling(default = "default_const_fn")]
+    pub const_fn: bool,
+    #[darling(default)]
+    pub codegen: Codegen,
+    #[darling(default)]
+    pub allow_shadowing: bool,
+    #[darling(default, rename = "crate")]
+    pub krate: Option<Path>,
+}
+
+/// Derive `const fn`s wherever possible unless opted out of.
+fn default_const_fn() -> bool {
+    true
+}
+
+/// Toggles for the attributes placed on derived items, parsed from `#[variantly(codegen(...))]`. All are enabled by default.
+#[derive(FromMeta, Debug, Clone, Copy)]
+#[darling(default)]
+pub struct Codegen {
+    /// `#[inline]` on every derived method.
+    pub inline: bool,
+    /// `#[must_use]` on derived methods without side effects whose return value is not already `#[must_use]`.
+    pub must_use: bool,
+    /// `#[track_caller]` on derived methods that may panic.
+    pub track_caller: bool,
+    /// `#[cold]` on the panic paths of derived methods.
+    pub cold: bool,
+    /// `#[automatically_derived]` on derived impl blocks.
+    pub automatically_derived: bool,
+}
+
+impl Default for Codegen {
+    fn default() -> Self {
+        Codegen {
+            inline: true,
+            must_use: true,
+            track_caller: true,
+            cold: true,
+            automatically_derived: true,
+        }
+    }
+}
+
+/// Casing strategies for coercing variant names into the names used within derived method names.
+#[derive(FromMeta, Debug, Default, Clone, Copy, PartialEq)]
+pub enum RenameAll {
+    /// Coerce names into snake_case, treating any configured acronyms as single words.
+    #[default]
+    #[darling(rename = "snake_case")]
+    SnakeCase,
+    /// Use names exactly as they are written.
+    #[darling(rename = "verbatim")]
+    Verbatim,
+}
+
+/// A list of string literals. EX: `acronyms("IPv4", "HTTP")`
+#[derive(Debug, Default)]
+pub struct StringList(pub Vec<String>);
+
+impl FromMeta for StringList {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                NestedMeta::Lit(Lit::Str(lit)) => Ok(lit.value()),
+                _ => Err(darling::Error::unexpected_type("non-string literal").with_span(item)),
+            })
+            .collect::<darling::Result<_>>()
+            .map(StringList)
+    }
+}
+
+/// A visibility parsed from a string literal. EX: `vis = "pub(crate)"`, or `vis = ""` for private.
+#[derive(Debug, Clone)]
+pub struct Vis(pub Visibility);
+
+impl FromMeta for Vis {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        syn::parse_str(value)
+            .map(Vis)
+            .map_err(|_| darling::Error::unknown_value(value))
+    }
+}
+
+impl EnumInput {
+    /// Coerce the given variant name into the name used within derived method names.
+    pub fn used_name(&self, name: &Ident) -> Ident {
+        match self.rename_all {
+            RenameAll::SnakeCase => format_ident!(
+                "{}",
+                to_snake_case_with_acronyms(&name.unraw().to_string(), &self.acronyms.0),
+                span = name.span()
+            ),
+            RenameAll::Verbatim => name.unraw(),
+        }
+    }
+
+    /// The families of derived methods selected at the enum level.
+    pub fn selection(&self) -> Selection {
+        Selection {
+            only: self.only.clone(),
+            exclude: self.exclude.clone(),
+        }
+    }
+}
 
 /// Struct for parsing relevant input to each variant of a variantly derived enum.
 #[derive(FromVariant, Debug)]
-#[darling(attributes(variantly))]
+#[darling(attributes(variantly), forward_attrs(doc))]
 pub struct VariantInput {
     pub ident: Ident,
+    pub attrs: Vec<Attribute>,
     #[darling(default)]
     pub rename: Option<Ident>,
+    #[darling(default)]
+    pub skip: bool,
+    #[darling(default)]
+    pub allow_shadowing: bool,
+    #[darling(default)]
+    pub only: Option<FamilyList>,
+    #[darling(default)]
+    pub exclude: Option<FamilyList>,
+    #[darling(default, rename = "vis")]
+    pub method_vis: Option<Vis>,
+    #[darling(default)]
+    pub payload_struct: Option<Override<Ident>>,
     pub fields: Fields<FieldParsed>,
 }
 
@@ -21,7 +172,6 @@ pub struct FieldParsed {
     pub ident: Option<Ident>,
     pub ty: Type,
     pub attrs: Vec<Attribute>,
-    pub vis: Visibility,
 }
 
 /// Parsed input to each variant of a variantly derived enum.
@@ -29,68 +179,435 @@ pub struct FieldParsed {
 pub struct VariantParsed {
     pub ident: Ident,
     pub used_name: Ident,
+    /// The variant's own `doc` attributes.
+    pub docs: Vec<Attribute>,
+    /// Visibility of the derived methods, which defaults to that of the enum.
+    pub vis: Visibility,
+    pub skip: bool,
+    /// Whether derived methods may shadow well-known trait methods without warning.
+    pub allow_shadowing: bool,
+    /// Families of derived methods selected at the enum level.
+    pub enum_selection: Selection,
+    /// Families of derived methods selected at the variant level.
+    pub selection: Selection,
+    pub payload_struct: Option<Override<Ident>>,
     pub fields: Fields<FieldParsed>,
 }
 
-impl From<VariantInput> for VariantParsed {
-    fn from(variant: VariantInput) -> Self {
+impl VariantParsed {
+    pub fn new(variant: VariantInput, enum_input: &EnumInput) -> Self {
         let ident = &variant.ident;
         VariantParsed {
-            used_name: format_ident!(
-                "{}",
-                to_snake_case(&variant.rename.unwrap_or_else(|| ident.clone()).to_string())
-            ),
+            used_name: enum_input.used_name(variant.rename.as_ref().unwrap_or(ident)),
             ident: variant.ident,
+            docs: variant.attrs,
+            vis: variant
+                .method_vis
+                .as_ref()
+                .or(enum_input.method_vis.as_ref())
+                .map_or_else(|| enum_input.vis.clone(), |vis| vis.0.clone()),
+            skip: variant.skip,
+            allow_shadowing: variant.allow_shadowing || enum_input.allow_shadowing,
+            enum_selection: enum_input.selection(),
+            selection: Selection {
+                only: variant.only,
+                exclude: variant.exclude,
+            },
+            payload_struct: variant.payload_struct,
             fields: variant.fields,
         }
     }
+
+    /// Names of each field as used within the names of methods concerning a single field.
+    /// Named fields are referred to by name, while tuple fields are referred to by position.
+    pub fn field_names(&self) -> Vec<String> {
+        self.fields
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| match &field.ident {
+                Some(ident) => ident.unraw().to_string(),
+                None => index.to_string(),
+            })
+            .collect()
+    }
+
+    /// The names of all methods derived for the variant, were it to use the given name.
+    pub fn method_names(&self, naming: &Naming, used_name: &Ident) -> Vec<Ident> {
+        let families = match self.fields.style {
+            Style::Unit => Family::UNIT,
+            _ => Family::VALUE,
+        };
+        let mut names: Vec<Ident> = Family::COMMON
+            .iter()
+            .chain(families)
+            .filter(|family| self.includes(**family))
+            .map(|family| naming.method(*family, used_name))
+            .collect();
+        self.field_names().iter().for_each(|field_name| {
+            let name = field_method_name(used_name, field_name);
+            names.extend(
+                Family::FIELD
+                    .iter()
+                    .filter(|family| self.includes(**family))
+                    .map(|family| naming.method(*family, &name)),
+            );
+        });
+        names
+    }
+
+    /// Check whether methods of the given family are selected at both the enum & variant level.
+    pub fn includes(&self, family: Family) -> bool {
+        self.enum_selection.includes(family) && self.selection.includes(family)
+    }
 }
 
-/// Attempt to parse an ItemEnum into a vec of parsed variants.
-pub fn try_parse_variants(item_enum: &ItemEnum) -> Result<Vec<VariantParsed>> {
-    item_enum
-        .variants
-        .iter()
-        .map(|variant| {
-            VariantInput::from_variant(variant)
-                .map(VariantInput::into)
-                .map_err(darling::Error::into)
+/// Keys accepted by `#[variantly(...)]` on the enum itself.
+const ENUM_KEYS: &[&str] = &[
+    "rename_all",
+    "acronyms",
+    "naming",
+    "style",
+    "only",
+    "exclude",
+    "vis",
+    "const_fn",
+    "codegen",
+    "allow_shadowing",
+    "crate",
+];
+
+/// Keys accepted by `#[variantly(...)]` on variants.
+const VARIANT_KEYS: &[&str] = &[
+    "rename",
+    "skip",
+    "allow_shadowing",
+    "only",
+    "exclude",
+    "vis",
+    "payload_struct",
+];
+
+/// Validate every `#[variantly(...)]` attribute of a variant & its fields ahead of parsing.
+/// Unknown keys are reported along with the most similar valid key, and attributes or keys that have no effect where
+/// they are placed are rejected.
+fn validate_variant_attributes(variant: &Variant) -> Result<()> {
+    let keys = validate_keys(
+        &variant.attrs,
+        "variants",
+        VARIANT_KEYS,
+        ("the enum", ENUM_KEYS),
+    );
+    let fields = variant.fields.iter().flat_map(|field| {
+        variantly_attrs(&field.attrs).map(|attr| {
+            let message = "`#[variantly(...)]` has no effect on fields, & may only be placed on the enum or its variants.";
+            syn::Error::new_spanned(&attr.path, message).into()
+        })
+    });
+    Error::collect(keys.into_iter().chain(fields).map(Err)).map(|_: Vec<()>| ())
+}
+
+fn variantly_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path.is_ident("variantly"))
+}
+
+/// Validate the keys of the `#[variantly(...)]` attributes placed in the given position.
+fn validate_keys(
+    attrs: &[Attribute],
+    position: &str,
+    keys: &[&str],
+    (other_position, other_keys): (&str, &[&str]),
+) -> Vec<Error> {
+    variantly_attrs(attrs)
+        // Malformed attributes are left to be reported when parsing.
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|nested| {
+            let path = match nested {
+                NestedMeta::Meta(meta) => meta.path().clone(),
+                NestedMeta::Lit(_) => return None,
+            };
+            let key = path
+                .get_ident()
+                .map(|ident| ident.unraw().to_string())
+                .unwrap_or_default();
+            let message = if keys.contains(&key.as_str()) {
+                return None;
+            } else if other_keys.contains(&key.as_str()) {
+                format!(
+                    "`{}` has no effect on {}, & may only be used on {}.",
+                    key, position, other_position
+                )
+            } else {
+                format!(
+                    "Unknown key `{}` for {}.{}",
+                    key,
+                    position,
+                    did_you_mean(&key, keys.iter().copied())
+                )
+            };
+            Some(syn::Error::new_spanned(path, message).into())
         })
         .collect()
 }
 
-/// Helper function for validation that requires comparing each variant with each other variant.
-/// Visits each pair only once and early returns on the first failure.
-pub fn validate_compare<F: Fn(&VariantParsed, &VariantParsed) -> Result<()>>(
-    variants: &Vec<VariantParsed>,
-    validations: Vec<F>,
-) -> Result<()> {
-    // Enumerate over the entire set.
-    variants
-        .as_slice()
+/// Parse the enum level input of an ItemEnum, along with the errors of any invalid keys.
+/// Invalid keys are dropped, so that the input of the remaining keys is still returned.
+pub fn try_parse_enum(item_enum: &ItemEnum) -> (EnumInput, Vec<Error>) {
+    let mut errors = vec![];
+    let mut derive_input = DeriveInput::from(item_enum.clone());
+    let (attrs, other_attrs): (Vec<Attribute>, Vec<Attribute>) = derive_input
+        .attrs
+        .drain(..)
+        .partition(|attr| attr.path.is_ident("variantly"));
+    derive_input.attrs = other_attrs;
+    // Every key has a default, so the input of an enum without any keys is always valid.
+    let mut enum_input =
+        EnumInput::from_derive_input(&derive_input).expect("enum input without keys is valid");
+
+    // Apply each key one at a time, keeping only those that parse along with the keys before them.
+    attrs.into_iter().flat_map(split_keys).for_each(|attr| {
+        let keys = validate_keys(
+            std::slice::from_ref(&attr),
+            "the enum",
+            ENUM_KEYS,
+            ("variants", VARIANT_KEYS),
+        );
+        if !keys.is_empty() {
+            return errors.extend(keys);
+        }
+
+        derive_input.attrs.push(attr);
+        match EnumInput::from_derive_input(&derive_input) {
+            Ok(input) => enum_input = input,
+            Err(err) => {
+                errors.push(err.into());
+                derive_input.attrs.pop();
+            }
+        }
+    });
+    enum_input.naming.style = enum_input.style;
+
+    let acronyms =
+        if enum_input.rename_all != RenameAll::SnakeCase && !enum_input.acronyms.0.is_empty() {
+            let message = "`acronyms` only affect the `snake_case` strategy of `rename_all`.";
+            Err(syn::Error::new(enum_input.ident.span(), message).into())
+        } else {
+            Ok(())
+        };
+    let selection = enum_input
+        .selection()
+        .validate(enum_input.ident.span())
+        .map_err(Error::from);
+    errors.extend(acronyms.err().into_iter().chain(selection.err()));
+
+    (enum_input, errors)
+}
+
+/// Split a `#[variantly(...)]` attribute into one attribute per key, such that each may be parsed on its own.
+/// Attributes that are not lists of keys are left as they are.
+fn split_keys(attr: Attribute) -> Vec<Attribute> {
+    match attr.parse_meta() {
+        Ok(Meta::List(list)) => list
+            .nested
+            .into_iter()
+            .map(|nested| syn::parse_quote!(#[variantly(#nested)]))
+            .collect(),
+        _ => vec![attr],
+    }
+}
+
+/// Attempt to parse the input of every variant of an ItemEnum.
+/// Returns the input of each variant that parsed successfully, along with the errors of those that failed.
+pub fn try_parse_variants(item_enum: &ItemEnum) -> (Vec<VariantInput>, Vec<Error>) {
+    let mut variants = vec![];
+    let mut errors = vec![];
+    item_enum.variants.iter().for_each(|variant| {
+        match validate_variant_attributes(variant)
+            .and_then(|_| Ok(VariantInput::from_variant(variant)?))
+        {
+            Ok(variant) => variants.push(variant),
+            Err(err) => errors.push(err),
+        }
+    });
+    (variants, errors)
+}
+
+/// A table of every method name derived for each variant, used to detect collisions between variants without comparing
+/// the methods of each pair of variants.
+pub struct MethodTable {
+    /// The names of the methods derived for each variant, by the index of the variant.
+    names: Vec<Vec<Ident>>,
+    /// The indices of the variants deriving each method name, in ascending order.
+    owners: HashMap<Ident, Vec<usize>>,
+}
+
+impl MethodTable {
+    pub fn new(variants: &[VariantParsed], naming: &Naming) -> Self {
+        let names: Vec<Vec<Ident>> = variants
+            .iter()
+            .map(|variant| variant.method_names(naming, &variant.used_name))
+            .collect();
+        let mut owners: HashMap<Ident, Vec<usize>> = HashMap::new();
+        names.iter().enumerate().for_each(|(index, names)| {
+            names.iter().for_each(|name| {
+                let owners = owners.entry(name.clone()).or_default();
+                if owners.last() != Some(&index) {
+                    owners.push(index);
+                }
+            })
+        });
+        MethodTable { names, owners }
+    }
+
+    /// The indices of the variants preceding the variant of the given index that derive a method of the same name.
+    pub fn collisions(&self, index: usize) -> Vec<usize> {
+        let mut collisions: Vec<usize> = self.names[index]
+            .iter()
+            .flat_map(|name| self.owners[name].iter().take_while(|owner| **owner < index))
+            .copied()
+            .collect();
+        collisions.sort_unstable();
+        collisions.dedup();
+        collisions
+    }
+
+    /// Validate that the methods derived for each variant will not collide with those derived for another.
+    /// Every colliding pair of variants is reported at once.
+    pub fn validate(&self, variants: &[VariantParsed], naming: &Naming) -> Result<()> {
+        let results = (0..variants.len()).flat_map(|b| {
+            self.collisions(b)
+                .into_iter()
+                .map(move |a| self.compare(&variants[a], a, &variants[b], b, naming))
+        });
+        Error::collect(results).map(|_| ())
+    }
+
+    /// Report the collisions between the methods of two variants.
+    fn compare(
+        &self,
+        a: &VariantParsed,
+        a_index: usize,
+        b: &VariantParsed,
+        b_index: usize,
+        naming: &Naming,
+    ) -> Result<()> {
+        if a.used_name == b.used_name {
+            let message = format!("`{}` cannot be coerced into a unique & idiomatic snake_case function name as it would collide with the `{}` variant of the same Enum. \
+                use the following attribute on this or the conflicting variant to resolve: `#[variantly(rename = \"some_other_name\")]`",
+                &a.ident, &b.ident);
+            return Err(syn::Error::new(a.ident.span(), message).into());
+        }
+
+        let a_names = &self.names[a_index];
+        let mut collisions: Vec<String> = vec![];
+        self.names[b_index]
+            .iter()
+            .filter(|name| a_names.contains(name))
+            .map(|name| format!("`{}`", name))
+            .for_each(|name| {
+                if !collisions.contains(&name) {
+                    collisions.push(name);
+                }
+            });
+
+        let suggestion = self.suggest_rename(b, naming);
+        let message = |this: &Ident, other: &Ident| {
+            format!("the {} method(s) derived for `{}` would collide with those derived for the `{}` variant of the same Enum. \
+                rename either variant to resolve, such as with the following attribute on `{}`: `#[variantly(rename = \"{}\")]`",
+                collisions.join(", "), this, other, &b.ident, suggestion)
+        };
+        let mut err = syn::Error::new(b.ident.span(), message(&b.ident, &a.ident));
+        err.combine(syn::Error::new(a.ident.span(), message(&a.ident, &b.ident)));
+        Err(err.into())
+    }
+
+    /// Suggest a name for the given variant whose methods will not collide with those of any variant.
+    fn suggest_rename(&self, variant: &VariantParsed, naming: &Naming) -> String {
+        (2usize..)
+            .map(|suffix| format_ident!("{}_{}", variant.used_name, suffix))
+            .find(|name| {
+                variant
+                    .method_names(naming, name)
+                    .iter()
+                    .all(|method| !self.owners.contains_key(method))
+            })
+            .map(|name| name.to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// Validate that no two methods derived for the same variant share a name, as may be caused by naming templates.
+pub fn validate_method_names(variant: &VariantParsed, naming: &Naming) -> Result<()> {
+    let names = variant.method_names(naming, &variant.used_name);
+    if let Some(reserved) = names
+        .iter()
+        .find(|name| naming::is_reserved(&name.to_string()))
+    {
+        let message = format!(
+            "`{}` would derive a method named `{}`, which is a keyword that can not be used as a method name. \
+            use the following attribute on this variant to resolve: `#[variantly(rename = \"{}_variant\")]`",
+            &variant.ident, reserved, &variant.used_name
+        );
+        return Err(syn::Error::new(variant.ident.span(), message).into());
+    }
+
+    let duplicates: Vec<String> = names
         .iter()
         .enumerate()
-        .try_for_each(|(index, variant_a)| -> Result<()> {
-            // Iterate over variants not visited already by the primary iterator.
-            variants[(index + 1)..variants.len()]
-                .iter()
-                .try_for_each(|variant_b| {
-                    // Run the current pair against all validation fns
-                    validations
-                        .iter()
-                        .try_for_each(|validation| validation(variant_a, variant_b))
-                })
+        .filter(|(index, name)| names[..*index].contains(name))
+        .map(|(_, name)| format!("`{}`", name))
+        .collect();
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        let message = format!(
+            "multiple methods named {} would be derived for `{}`. adjust the `naming` templates of the Enum to resolve.",
+            duplicates.join(", "),
+            &variant.ident
+        );
+        Err(syn::Error::new(variant.ident.span(), message).into())
+    }
+}
+
+/// Find the derived methods of a variant that would shadow well-known trait methods, along with the trait of each &
+/// whether the trait method takes `self`.
+/// Shadowing is not reported for variants that allow it through `#[variantly(allow_shadowing)]`.
+pub fn shadowed_trait_methods(
+    variant: &VariantParsed,
+    naming: &Naming,
+) -> Vec<(Ident, &'static str, bool)> {
+    if variant.allow_shadowing {
+        return vec![];
+    }
+    variant
+        .method_names(naming, &variant.used_name)
+        .into_iter()
+        .filter_map(|method| {
+            naming::shadowed_trait(&method.unraw().to_string())
+                .map(|(trait_path, receiver)| (method, trait_path, receiver))
         })
+        .collect()
 }
 
-/// Validate that the used names for each variant will not cause naming conflicts.
-pub fn compare_used_names(a: &VariantParsed, b: &VariantParsed) -> Result<()> {
-    if a.used_name == b.used_name {
-        let message = format!("`{}` cannot be coerced into a unique & idiomatic snake_case function name as it would collide with the `{}` variant of the same Enum. \
-            use the following attribute on this or the conflicting variant to resolve: `#[variantly(rename = \"some_other_name\")]`",
-            &a.ident, &b.ident);
-        Err(syn::Error::new(a.ident.span(), message).into())
-    } else {
-        Ok(())
+/// Validate that at most one of `only` & `exclude` is used on a variant.
+pub fn validate_selection(variant: &VariantParsed) -> Result<()> {
+    Ok(variant.selection.validate(variant.ident.span())?)
+}
+
+/// Validate that a payload struct is only requested for variants with named fields.
+pub fn validate_payload_struct(variant: &VariantParsed) -> Result<()> {
+    match (&variant.payload_struct, &variant.fields.style) {
+        (Some(_), Style::Tuple) | (Some(_), Style::Unit) => {
+            let message = format!(
+                "`#[variantly(payload_struct)]` requires named fields, but `{}` is not a struct-like variant.",
+                &variant.ident
+            );
+            Err(syn::Error::new(variant.ident.span(), message).into())
+        }
+        _ => Ok(()),
     }
 }
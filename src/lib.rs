@@ -93,7 +93,7 @@
 //! assert_eq!(None, color.rgb());
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
 //!
 //! ### `pub fn {variant_name}_ref(&self) -> Option(&...)`
 //! If the enum is of the given variant, returns a `Some` containing a ref to the inner variant value. Otherwise, return None.
@@ -119,7 +119,7 @@
 //! assert_eq!(None, color.rgb_ref());
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
 //!
 //! ### `pub fn {variant_name}_mut(&mut self) -> Option(&mut...)`
 //! If the enum is of the given variant, returns a `Some` containing a mutable ref to the inner variant value. Otherwise, return None.
@@ -144,7 +144,7 @@
 //! assert_eq!(None, color.rgb_mut());
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
 //!
 //! ### `pub fn {variant_name}_or<E>(self, err: E) -> Result<(...), E>`
 //! If the enum is of the given variant, returns a [`Result::Ok`] containing the inner value. Otherwise, return [`Result::Err`] containing `err`.
@@ -170,7 +170,7 @@
 //! assert_eq!(Err("Error: Not an HSV!"), result);
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available for all variant types. Unit variants such as Color::FromOutOfSpace use `()` in place of the inner values*
 //!
 //! ### `pub fn {variant_name}_ref_or<E>(&self, err: E) -> Result<(&...), E>`
 //! If the enum is of the given variant, returns a `Result::Ok` containing a ref to the inner value. Otherwise, return `Result::Err` containing `err`.
@@ -196,7 +196,7 @@
 //! assert_eq!(Err("Error: Not an HSV!"), result);
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
 //!
 //! ### `pub fn {variant_name}_mut_or<E>(&mut self, err: E) -> Result<(&mut...), E>`
 //! If the enum is of the given variant, returns a `Result::Ok` containing a mutable ref to the inner value. Otherwise, return `Result::Err` containing `err`.
@@ -222,7 +222,7 @@
 //! assert_eq!(Err("Error: Not an HSV!"), result);
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
 //!
 //! ### `pub fn {variant_name}_or_else<E, F: FnOnce() -> E>(self, f: F) -> Result<(...), E>`
 //! If the enum is of the given variant, returns a [`Result::Ok`] containing the inner variant value. Otherwise, calls `f` to calculate a [`Result::Err`].
@@ -248,7 +248,7 @@
 //! assert_eq!(Err("This is an expensive error to create."), result);
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available for all variant types. Unit variants such as Color::FromOutOfSpace use `()` in place of the inner values*
 //!
 //! ### `pub fn {variant_name}_ref_or_else<E, F: FnOnce() -> E>(&self, f: F) -> Result<(&...), E>`
 //! If the enum is of the given variant, returns a `Result::Ok` containing a ref to the inner variant value. Otherwise, calls `f` to calculate a `Result::Err`.
@@ -274,7 +274,7 @@
 //! assert_eq!(Err("This is an expensive error to create."), result);
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
 //!
 //! ### `pub fn {variant_name}_mut_or_else<E, F: FnOnce() -> E>(&mut self, f: F) -> Result<(&mut...), E>`
 //! If the enum is of the given variant, returns a `Result::Ok` containing a mut ref to the inner variant value. Otherwise, calls `f` to calculate a `Result::Err`.
@@ -300,7 +300,7 @@
 //! assert_eq!(Err("This is an expensive error to create."), result);
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
 //!
 //! ## Accessing Inner Values
 //! Use the below methods to easily access the inner value of a given variant.
@@ -309,7 +309,8 @@
 //! Returns the contained value.
 //!
 //! #### Panics
-//! Panics if the enum is not of the given variant with the custom message `msg`.
+//! Panics if the enum is not of the given variant with the custom message `msg`, followed by the name of the variant
+//! that was found. EX: ``This should be an hsv: found a `Color::Grey` value``
 //!
 //! #### Example
 //! ```
@@ -332,13 +333,14 @@
 //! assert_eq!(grey, 10);
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available for all variant types. Unit variants such as Color::FromOutOfSpace use `()` in place of the inner values*
 //!
 //! ### `pub fn unwrap_{variant_name}(self) -> (...)`
 //! Returns the contained value.
 //!
 //! #### Panics
-//! Panics if the enum is not of the given variant.
+//! Panics if the enum is not of the given variant with a message naming the variant that was found.
+//! EX: ``called `Color::unwrap_hsv()` on a `Color::Grey` value``
 //!
 //! #### Example
 //! ```
@@ -361,7 +363,7 @@
 //! assert_eq!(grey, 10);
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available for all variant types. Unit variants such as Color::FromOutOfSpace use `()` in place of the inner values*
 //!
 //! ### `pub fn unwrap_or_{variant_name}(self, fallback: (...)) -> (...)`
 //! Returns the contained value if the enum is of the given variant, otherwise returns the provided `fallback`.
@@ -387,7 +389,7 @@
 //! assert_eq!(color, (4, 5, 6));
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
 //!
 //! ### `pub fn unwrap_or_else_{variant_name}<F: FnOnce() -> (...)>(self, f: F) -> (...)`
 //! Returns the contained value if the enum is of the given variant, otherwise computes a fallback from `f`.
@@ -413,7 +415,54 @@
 //! assert_eq!((h, s, v), (4, 5, 6));
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//!
+//! ### `pub fn {variant_name}_{field_name}_ref(&self) -> Option<&...>`
+//! If the enum is of the given variant, returns a `Some` containing a ref to a single field of the variant. Otherwise, return None.
+//! Tuple fields are named by their position, such as `.rgb_0_ref()` for the first value of `Color::RGB`.
+//!
+//! #### Example
+//! ```
+//! # #[derive(variantly::Variantly, Debug, PartialEq)]
+//! # enum Color {
+//! #     RGB(u8, u8, u8),
+//! #     HSV(u8, u8, u8),
+//! #     Grey(u8),
+//! #     FromOutOfSpace,
+//! #     #[variantly(rename = "darkness")]
+//! #     Black,
+//! # }
+//! let color = Color::HSV(1,2,3);
+//! assert_eq!(Some(&3), color.hsv_2_ref());
+//!
+//! let color = Color::FromOutOfSpace;
+//! assert_eq!(None, color.hsv_2_ref());
+//! ```
+//!
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//!
+//! ### `pub fn {variant_name}_{field_name}_mut(&mut self) -> Option<&mut ...>`
+//! If the enum is of the given variant, returns a `Some` containing a mutable ref to a single field of the variant. Otherwise, return None.
+//!
+//! #### Example
+//! ```
+//! # #[derive(variantly::Variantly, Debug, PartialEq)]
+//! # enum Color {
+//! #     RGB(u8, u8, u8),
+//! #     HSV(u8, u8, u8),
+//! #     Grey(u8),
+//! #     FromOutOfSpace,
+//! #     #[variantly(rename = "darkness")]
+//! #     Black,
+//! # }
+//! let mut color = Color::HSV(1,2,3);
+//! if let Some(v) = color.hsv_2_mut() {
+//!     *v = 4;
+//! }
+//! assert_eq!(Color::HSV(1, 2, 4), color);
+//! ```
+//!
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
 //!
 //! ## Testing Variant Type
 //! Use the below methods to test whether a variant is of the given type.
@@ -509,7 +558,7 @@
 //! );
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
 //!
 //! ### `pub fn or_{variant_name}(self, enum_b: GivenEnum) -> GivenEnum`
 //! Returns `self` if it is of the given variant, otherwise returns `enum_b`.
@@ -558,7 +607,104 @@
 //! );
 //! ```
 //!
-//! *Note: Available only for tuple-style variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//!
+//! ### `pub fn with_{variant_name}_{field_name}(self, value: ...) -> Self`
+//! Returns the enum with a single field replaced by `value` if it is of the given variant, otherwise returns the enum as is.
+//! Tuple fields are named by their position, such as `.with_rgb_0()` for the first value of `Color::RGB`.
+//!
+//! #### Example
+//! ```
+//! # #[derive(variantly::Variantly, Debug, PartialEq)]
+//! # enum Color {
+//! #     RGB(u8, u8, u8),
+//! #     HSV(u8, u8, u8),
+//! #     Grey(u8),
+//! #     FromOutOfSpace,
+//! #     #[variantly(rename = "darkness")]
+//! #     Black,
+//! # }
+//! let color = Color::HSV(1,2,3);
+//! assert_eq!(Color::HSV(1,2,4), color.with_hsv_2(4));
+//!
+//! let color = Color::FromOutOfSpace;
+//! assert_eq!(Color::FromOutOfSpace, color.with_hsv_2(4));
+//! ```
+//!
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//!
+//! ### `pub fn set_{variant_name}_{field_name}(&mut self, value: ...) -> Option<...>`
+//! If the enum is of the given variant, replaces a single field with `value` and returns the old value in a `Some`. Otherwise, leaves the enum unchanged and returns None.
+//!
+//! #### Example
+//! ```
+//! # #[derive(variantly::Variantly, Debug, PartialEq)]
+//! # enum Color {
+//! #     RGB(u8, u8, u8),
+//! #     HSV(u8, u8, u8),
+//! #     Grey(u8),
+//! #     FromOutOfSpace,
+//! #     #[variantly(rename = "darkness")]
+//! #     Black,
+//! # }
+//! let mut color = Color::HSV(1,2,3);
+//! assert_eq!(Some(3), color.set_hsv_2(4));
+//! assert_eq!(Color::HSV(1,2,4), color);
+//!
+//! let mut color = Color::FromOutOfSpace;
+//! assert_eq!(None, color.set_hsv_2(4));
+//! ```
+//!
+//! *Note: Available only for tuple-style & struct-like variants such as Color::RGB(200, 40, 180), or Color::Grey(10)*
+//!
+//! # Struct-Like Variants
+//! Variants with named fields receive the same methods as tuple-style variants. Their fields are exposed as a tuple in declaration order:
+//! ```
+//! #[derive(variantly::Variantly)]
+//! enum Shape {
+//!     Circle { radius: u32 },
+//!     Rectangle { width: u32, height: u32 },
+//! }
+//!
+//! let shape = Shape::Rectangle { width: 3, height: 4 };
+//! assert_eq!(shape.rectangle_ref(), Some((&3, &4)));
+//!
+//! let (width, height) = shape.unwrap_rectangle();
+//! assert_eq!((width, height), (3, 4));
+//!
+//! let shape = Shape::Circle { radius: 5 };
+//! assert_eq!(shape.unwrap_circle(), 5);
+//! ```
+//!
+//! ## Payload Structs
+//! Tuples lose the names of the fields they were built from. Place `#[variantly(payload_struct)]` on a struct-like variant to instead
+//! generate a companion struct named `{EnumName}{VariantName}Payload`, along with borrowed `...PayloadRef` & `...PayloadMut` versions holding
+//! references to each field. Methods returning the variant's values by value, by reference or by mutable reference will then return the
+//! matching struct, and a `From` impl converts the owned struct back into the enum:
+//! ```
+//! #[derive(variantly::Variantly)]
+//! enum Color {
+//!     Grey(u8),
+//!     #[variantly(payload_struct)]
+//!     Custom { name: String, alpha: u8 },
+//! }
+//!
+//! let mut color = Color::Custom { name: String::from("teal"), alpha: 128 };
+//!
+//! let ColorCustomPayloadRef { name, alpha } = color.custom_ref().unwrap();
+//! assert_eq!((name.as_str(), *alpha), ("teal", 128));
+//!
+//! let payload = color.custom_mut().unwrap();
+//! *payload.alpha = 255;
+//!
+//! let ColorCustomPayload { name, alpha } = color.unwrap_custom();
+//! assert_eq!((name.as_str(), alpha), ("teal", 255));
+//!
+//! let color = Color::from(ColorCustomPayload { name, alpha: 0 });
+//! assert!(color.is_custom());
+//! ```
+//! The name of the generated struct can be set with `#[variantly(payload_struct = "SomeName")]`, which will also name the borrowed
+//! versions `SomeNameRef` & `SomeNameMut`.
 //!
 //! # Renaming Methods
 //! The `variantly` attribute may be placed on a variant in order to customize the resulting method names. The value set against `rename` inside the attribute will be used in place of the snake_cased variant name when constructing derived method names.
@@ -590,6 +736,406 @@
 //! Without the `rename` attribute in the above, both variants would create conflicting methods such as `.is_abc()` due to the coercion to snake_case.
 //! This is avoided by using the rename input to create meaningful and unique fn names.
 //!
+//! Conflicts are detected between every derived method, not only between variant names. For example, the `.foo_ref_or()` method of a
+//! `Foo` variant would conflict with the `.foo_ref_or()` method of a unit `FooRef` variant, and the `.is_not_foo()` method of a `Foo` variant would
+//! conflict with the `.is_not_foo()` method of a `NotFoo` variant. Each conflict is reported on both variants, along with a suggested `rename`.
+//!
+//! ```compile_fail
+//! #[derive(variantly::Variantly)]
+//! enum Unit {
+//!     Foo(u8),
+//!     // error: the `foo_ref_or`, `foo_ref_or_else` method(s) derived for `FooRef` would collide with those derived for the `Foo` variant of the same Enum.
+//!     // rename either variant to resolve, such as with the following attribute on `FooRef`: `#[variantly(rename = "foo_ref_2")]`
+//!     FooRef,
+//! }
+//! ```
+//! ```compile_fail
+//! #[derive(variantly::Variantly)]
+//! enum Negated {
+//!     Foo(u8),
+//!     // error: the `is_not_foo` method(s) derived for `NotFoo` would collide with those derived for the `Foo` variant of the same Enum.
+//!     NotFoo(u8),
+//! }
+//! ```
+//! ```compile_fail
+//! #[derive(variantly::Variantly)]
+//! enum Chained {
+//!     Foo(u8),
+//!     // error: the `and_then_foo` method(s) derived for `ThenFoo` would collide with those derived for the `Foo` variant of the same Enum.
+//!     ThenFoo(u8),
+//! }
+//! ```
+//! Applying the suggested `rename` resolves each conflict:
+//! ```
+//! #[derive(variantly::Variantly, Debug, PartialEq)]
+//! enum Resolved {
+//!     Foo(u8),
+//!     #[variantly(rename = "foo_ref_2")]
+//!     FooRef,
+//!     #[variantly(rename = "not_foo_2")]
+//!     NotFoo(u8),
+//!     #[variantly(rename = "then_foo_2")]
+//!     ThenFoo(u8),
+//! }
+//!
+//! assert!(Resolved::FooRef.is_foo_ref_2());
+//! assert!(Resolved::Foo(1).is_not_not_foo_2());
+//! assert_eq!(Resolved::ThenFoo(1).and_then_then_foo_2(|value| value + 1), Resolved::ThenFoo(2));
+//! ```
+//!
+//! Every invalid attribute & conflict of an enum is reported at once, rather than only the first to be found:
+//! ```compile_fail
+//! #[derive(variantly::Variantly)]
+//! enum Color {
+//!     // error: Unknown key `renmae` for variants. Did you mean `rename`?
+//!     #[variantly(renmae = "hsl")]
+//!     HSL(u8, u8, u8),
+//!     // error: `RGB` cannot be coerced into a unique & idiomatic snake_case function name as it would collide with the `Rgb` variant of the same Enum.
+//!     RGB(u8, u8, u8),
+//!     Rgb(u8, u8, u8),
+//! }
+//! ```
+//!
+//! ## Renaming All Variants
+//! The `variantly` attribute may also be placed on the enum itself to control how every variant name is coerced into a method name
+//! through `rename_all`. The default `"snake_case"` strategy may be given a list of `acronyms` that will each be treated as a single word:
+//! ```
+//! #[derive(variantly::Variantly)]
+//! #[variantly(acronyms("IPv4", "HTTP"))]
+//! enum Address {
+//!     IPv4Addr(u32),
+//!     HTTPStatus(u16),
+//! }
+//!
+//! let address = Address::IPv4Addr(1);
+//! assert!(address.is_ipv4_addr());
+//! ```
+//! Without `acronyms`, the above would derive methods such as `.is_i_pv_4_addr()`.
+//!
+//! The `"verbatim"` strategy uses variant names exactly as they are written:
+//! ```
+//! #[derive(variantly::Variantly)]
+//! #[variantly(rename_all = "verbatim")]
+//! enum Shape {
+//!     Circle(u32),
+//!     #[variantly(rename = "square")]
+//!     Square(u32),
+//! }
+//!
+//! let shape = Shape::Circle(5);
+//! assert!(shape.is_Circle());
+//! assert!(!shape.is_square());
+//! ```
+//! Either strategy is subject to the same checks for conflicting method names as `rename`.
+//!
+//! ## Naming Templates
+//! The names of each family of derived methods may be customized through templates placed in the enum level `naming` attribute.
+//! The `{}` placeholder within a template is replaced by the name of the variant, or for methods concerning a single field, the name
+//! of the variant followed by the name of the field. A `prefix` & `suffix` may also be applied to every derived method:
+//! ```
+//! #[derive(variantly::Variantly)]
+//! #[variantly(naming(unwrap = "into_{}_unchecked", ref = "as_{}", prefix = "v_"))]
+//! enum Color {
+//!     RGB(u8, u8, u8),
+//!     Grey(u8),
+//! }
+//!
+//! let color = Color::Grey(128);
+//! assert_eq!(color.v_as_grey(), Some(&128));
+//! assert!(color.v_is_grey());
+//! assert_eq!(color.v_into_grey_unchecked(), 128);
+//! ```
+//! The keys for each family along with their default templates are:
+//!
+//! | Key | Template | Key | Template | Key | Template |
+//! |-----|----------|-----|----------|-----|----------|
+//! | `is` | `is_{}` | `value` | `{}` | `ok` | `ok_{}` |
+//! | `is_not` | `is_not_{}` | `value_or` | `{}_or` | `ok_or` | `ok_or_{}` |
+//! | `and` | `and_{}` | `value_or_else` | `{}_or_else` | `ok_or_else` | `ok_or_else_{}` |
+//! | `or` | `or_{}` | `ref` | `{}_ref` | `field_ref` | `{}_ref` |
+//! | `and_then` | `and_then_{}` | `ref_or` | `{}_ref_or` | `field_mut` | `{}_mut` |
+//! | `or_else` | `or_else_{}` | `ref_or_else` | `{}_ref_or_else` | `with` | `with_{}` |
+//! | `expect` | `expect_{}` | `mut` | `{}_mut` | `set` | `set_{}` |
+//! | `unwrap` | `unwrap_{}` | `mut_or` | `{}_mut_or` | | |
+//! | `unwrap_or` | `unwrap_or_{}` | `mut_or_else` | `{}_mut_or_else` | | |
+//! | `unwrap_or_else` | `unwrap_or_else_{}` | | | | |
+//!
+//! Templates, prefixes & suffixes must produce valid method names. A prefix may not start with a digit, for example:
+//! ```compile_fail
+//! #[derive(variantly::Variantly)]
+//! // error: `1` would not produce a valid method name
+//! #[variantly(naming(prefix = "1"))]
+//! enum Color {
+//!     Grey(u8),
+//! }
+//! ```
+//!
+//! ## API Guidelines Style
+//! The enum level `style` attribute selects a preset of templates. The `"api_guidelines"` preset follows the `as_`, `into_` & `is_`
+//! conversion conventions of the [Rust API guidelines](https://rust-lang.github.io/api-guidelines/naming.html), replacing the standard names entirely:
+//! ```
+//! #[derive(variantly::Variantly)]
+//! #[variantly(style = "api_guidelines")]
+//! enum Color {
+//!     RGB(u8, u8, u8),
+//!     Grey(u8),
+//! }
+//!
+//! let mut color = Color::Grey(128);
+//! assert!(color.is_grey());
+//! assert_eq!(color.as_grey(), Some(&128));
+//! assert_eq!(color.as_grey_mut(), Some(&mut 128));
+//! assert_eq!(color.into_grey(), Some(128));
+//!
+//! let color = Color::RGB(1, 2, 3);
+//! assert_eq!(color.try_into_grey("Not grey"), Err("Not grey"));
+//! ```
+//! | Key | Template | Key | Template |
+//! |-----|----------|-----|----------|
+//! | `value` | `into_{}` | `mut` | `as_{}_mut` |
+//! | `value_or` | `try_into_{}` | `mut_or` | `try_as_{}_mut` |
+//! | `value_or_else` | `try_into_{}_or_else` | `mut_or_else` | `try_as_{}_mut_or_else` |
+//! | `ref` | `as_{}` | `field_ref` | `as_{}` |
+//! | `ref_or` | `try_as_{}` | `field_mut` | `as_{}_mut` |
+//! | `ref_or_else` | `try_as_{}_or_else` | | |
+//!
+//! All other families keep their standard templates. Templates set through `naming` take precedence over those of the preset.
+//!
+//! # Selecting Methods
+//! The families of derived methods may be restricted through the `only` & `exclude` attributes, at either the enum or
+//! variant level. Families are referred to by the same keys used for [naming templates](#naming-templates). A method is
+//! derived only when selected at both levels, which allows panicking accessors to be banned for an entire enum, or the
+//! generated code for large enums to be kept small:
+//!
+//! ```rust
+//! use variantly::Variantly;
+//!
+//! #[derive(Variantly)]
+//! #[variantly(exclude(unwrap, expect))]
+//! enum Color {
+//!     RGB(u8, u8, u8),
+//!     #[variantly(only(is, ref, mut))]
+//!     Grey(u8),
+//! }
+//!
+//! let color = Color::Grey(128);
+//! assert!(color.is_grey());
+//! assert_eq!(color.grey_ref(), Some(&128));
+//! // Neither `color.unwrap_rgb()` nor `color.grey()` exist.
+//! ```
+//! `only` & `exclude` may not both be used on the same item.
+//!
+//! # Skipping Variants
+//! Variants marked with `#[variantly(skip)]` are left out of all derived methods. This is useful for internal or
+//! `#[doc(hidden)]` variants that should not be exposed through the public API. Skipped variants are also excluded from
+//! validation, so they will never cause method name collisions.
+//!
+//! ```rust
+//! use variantly::Variantly;
+//!
+//! #[derive(Variantly)]
+//! enum Color {
+//!     HSL(u8, u8, u8),
+//!     #[doc(hidden)]
+//!     #[variantly(skip)]
+//!     Internal(String),
+//! }
+//!
+//! let color = Color::HSL(1, 2, 3);
+//! assert!(color.is_hsl());
+//! // `color.is_internal()` does not exist.
+//! ```
+//!
+//! # Visibility
+//! Derived methods share the visibility of the enum by default. This may be changed for all methods through the enum level
+//! `vis` attribute, or for the methods of a single variant through the variant level `vis` attribute, which takes
+//! precedence. An empty string declares private methods:
+//!
+//! ```rust
+//! mod colors {
+//!     #[derive(variantly::Variantly)]
+//!     #[variantly(vis = "pub(crate)")]
+//!     pub enum Color {
+//!         RGB(u8, u8, u8),
+//!         #[variantly(vis = "")]
+//!         Grey(u8),
+//!     }
+//! }
+//!
+//! let color = colors::Color::RGB(1, 2, 3);
+//! assert!(color.is_rgb());
+//! // `color.is_grey()` is private to the `colors` module.
+//! ```
+//! Methods derived for private enums are exempt from the `dead_code` lint.
+//!
+//! # Const Methods
+//! Derived methods that neither drop nor mutate values are declared as `const fn`. These are the `is_{variant_name}`,
+//! `is_not_{variant_name}`, `{variant_name}_ref` & `{variant_name}_{field_name}_ref` methods, which may then be used within
+//! constant expressions:
+//!
+//! ```rust
+//! #[derive(variantly::Variantly)]
+//! enum Mode {
+//!     Fast,
+//!     Slow(u8),
+//! }
+//!
+//! const DEFAULT_MODE: Mode = Mode::Fast;
+//! const _: () = assert!(DEFAULT_MODE.is_fast());
+//! ```
+//! This may be opted out of through the enum level `#[variantly(const_fn = false)]` attribute.
+//!
+//! # Code Generation Attributes
+//! Derived items are annotated with the following attributes, each of which may be disabled through the enum level
+//! `codegen` attribute:
+//!
+//! | Key | Effect |
+//! |-----|--------|
+//! | `inline` | `#[inline]` on every derived method. |
+//! | `must_use` | `#[must_use]` on methods without side effects, such as `is_{variant_name}` & `{variant_name}_ref`. |
+//! | `track_caller` | `#[track_caller]` on `unwrap_{variant_name}` & `expect_{variant_name}`, so that panics report the location of the caller. |
+//! | `cold` | `#[cold]` on the panic paths of `unwrap_{variant_name}` & `expect_{variant_name}`. |
+//! | `automatically_derived` | `#[automatically_derived]` on derived impl blocks. |
+//!
+//! ```rust
+//! #[derive(variantly::Variantly)]
+//! #[variantly(codegen(inline = false, must_use = false))]
+//! enum Color {
+//!     RGB(u8, u8, u8),
+//!     Grey(u8),
+//! }
+//!
+//! Color::Grey(128).is_grey();
+//! ```
+//!
+//! By default, ignoring the result of such a method is linted against:
+//! ```compile_fail
+//! #![deny(unused_must_use)]
+//!
+//! #[derive(variantly::Variantly)]
+//! enum Color {
+//!     RGB(u8, u8, u8),
+//!     Grey(u8),
+//! }
+//!
+//! // error: unused return value of `Color::is_grey` that must be used
+//! Color::Grey(128).is_grey();
+//! ```
+//!
+//! # Documentation
+//! Each derived method is documented with a summary of its behavior that links to its variant, followed by the variant's
+//! own docs. The name of the variant is also registered as a `doc(alias)`, so that searching the docs for a variant finds
+//! all of its derived methods.
+//!
+//! # Keywords & Non-ASCII Names
+//! Methods whose names would be keywords, such as those derived for a `Match` variant, are declared as raw identifiers:
+//!
+//! ```rust
+//! #[derive(variantly::Variantly)]
+//! enum Token {
+//!     Match(u8),
+//!     Loop,
+//! }
+//!
+//! assert_eq!(Token::Match(1).r#match(), Some(1));
+//! assert!(Token::Loop.is_loop());
+//! ```
+//! The keywords `self`, `Self`, `super` & `crate` can not be used even as raw identifiers, so variants that would derive
+//! methods with these names must be renamed through `#[variantly(rename = "...")]`.
+//!
+//! Variant names containing non-ASCII characters are coerced into snake_case at each change in case, such that a
+//! `ÜberGroß` variant derives methods such as `is_über_groß`.
+//!
+//! # Shadowed Trait Methods
+//! Variants named after well-known trait methods, such as `Clone`, `Into` or `Map`, derive inherent methods such as
+//! `.clone()` that shadow the trait methods of the same name. Trait functions without a `self` receiver, such as
+//! `From::from`, are shadowed when called through the path of the enum instead, such as `Enum::from(...)`. A compile time
+//! warning is emitted for each such method, which
+//! may be resolved by renaming the variant, or silenced through the `allow_shadowing` attribute on either the enum or the
+//! variant:
+//!
+//! ```rust
+//! #[derive(variantly::Variantly, Clone)]
+//! enum Operation {
+//!     #[variantly(allow_shadowing)]
+//!     Map(u8),
+//!     #[variantly(rename = "cloned_value")]
+//!     Clone(u8),
+//! }
+//!
+//! assert_eq!(Operation::Map(1).map(), Some(1));
+//! assert_eq!(Operation::Clone(2).cloned_value(), Some(2));
+//! ```
+//!
+//! Without either attribute, the warning fails to compile where deprecation warnings are denied:
+//! ```compile_fail
+//! #![deny(deprecated)]
+//!
+//! #[derive(variantly::Variantly, Clone)]
+//! enum Operation {
+//!     // warning: the `clone` method derived for `Operation::Clone` shadows `Clone::clone` when called with method syntax.
+//!     Clone(u8),
+//! }
+//! ```
+//!
+//! # `no_std`
+//! Derived methods only refer to items of `core`, through absolute paths such as `::core::option::Option`, so the
+//! derive may be used within `#![no_std]` crates.
+//! Paths are always absolute, so derived methods are unaffected by local items named `std`, `core` or `bool`.
+//!
+//! # Facade Crates
+//! The enum level `crate` attribute accepts the path through which `variantly` is accessed, such as when it is re-exported
+//! by a facade crate. As `variantly` only exports the derive itself, derived code does not refer to any items through this
+//! path, so the attribute does not change the derived methods. It is accepted for forward compatibility, & is only checked
+//! to resolve from the deriving crate:
+//!
+//! ```rust
+//! mod facade {
+//!     pub(crate) use ::variantly;
+//! }
+//!
+//! #[derive(variantly::Variantly)]
+//! #[variantly(crate = "facade::variantly")]
+//! enum Color {
+//!     RGB(u8, u8, u8),
+//!     Grey(u8),
+//! }
+//! ```
+//!
+//! # Attribute Placement
+//! `#[variantly(...)]` attributes may be placed on the enum & its variants, but not on fields. Each key is validated
+//! against where it is placed, so keys such as `rename` that only apply to variants are rejected on the enum, while
+//! misspelled keys are reported along with the closest valid key:
+//!
+//! ```compile_fail
+//! #[derive(variantly::Variantly)]
+//! enum Color {
+//!     // error: Unknown key `renmae` for variants. Did you mean `rename`?
+//!     #[variantly(renmae = "hsl")]
+//!     HSL(u8, u8, u8),
+//! }
+//! ```
+//!
+//! Invalid keys & variants are left out of the derive, while methods are still derived for the rest of the enum. As
+//! such, each mistake causes a single error, rather than also failing every call to a derived method:
+//!
+//! ```compile_fail
+//! #[derive(variantly::Variantly)]
+//! // error: Unknown key `rename_al` for the enum. Did you mean `rename_all`?
+//! #[variantly(rename_al = "snake_case")]
+//! enum Color {
+//!     RGB(u8, u8, u8),
+//!     // error: `Hsl` cannot be coerced into a unique & idiomatic snake_case function name as it would collide with the `HSL` variant of the same Enum.
+//!     #[variantly(rename = "hsl")]
+//!     Hsl(u8, u8, u8),
+//!     HSL(u8, u8, u8),
+//! }
+//!
+//! // The methods of valid variants still resolve.
+//! assert!(Color::RGB(255, 0, 0).is_rgb());
+//! assert!(Color::Hsl(0, 100, 50).is_hsl());
+//! ```
+//!
 //! #### License
 //!
 //! <sup>
@@ -611,8 +1157,11 @@ extern crate proc_macro;
 mod idents;
 
 mod derive;
+mod docs;
 mod error;
 mod input;
+mod naming;
+mod payload;
 
 use derive::derive_variantly_fns;
 use proc_macro::TokenStream;
@@ -622,5 +1171,5 @@ use syn::{parse_macro_input, ItemEnum};
 #[proc_macro_derive(Variantly, attributes(variantly))]
 pub fn variantly(input: TokenStream) -> TokenStream {
     let item_enum = parse_macro_input!(input as ItemEnum);
-    derive_variantly_fns(item_enum).unwrap_or_else(|err| err.into_compile_error())
+    derive_variantly_fns(item_enum)
 }
@@ -0,0 +1,410 @@
+//! Name derived methods from configurable templates.
+use crate::error::did_you_mean;
+use darling::{Error as DarlingError, FromMeta};
+use proc_macro2::Span;
+use std::collections::HashMap;
+use syn::{ext::IdentExt, Ident, Lit, LitStr, Meta, NestedMeta};
+
+/// Declare the families of derived methods along with the key used to configure each through
+/// `#[variantly(naming(...))]` and the template used to name them by default.
+macro_rules! families {
+    ($($family:ident => $key:literal, $template:literal;)*) => {
+        /// A family of derived methods that share a naming template.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Family {
+            $($family,)*
+        }
+
+        impl Family {
+            /// Every family of derived methods.
+            pub const ALL: &'static [Family] = &[$(Family::$family,)*];
+
+            /// The key used to configure the family within `#[variantly(naming(...))]`.
+            pub fn key(self) -> &'static str {
+                match self {
+                    $(Family::$family => $key,)*
+                }
+            }
+
+            /// The template used to name methods of the family by the standard style.
+            pub fn default_template(self) -> &'static str {
+                match self {
+                    $(Family::$family => $template,)*
+                }
+            }
+        }
+    };
+}
+
+families! {
+    Is => "is", "is_{}";
+    IsNot => "is_not", "is_not_{}";
+    And => "and", "and_{}";
+    Or => "or", "or_{}";
+    AndThen => "and_then", "and_then_{}";
+    OrElse => "or_else", "or_else_{}";
+    Expect => "expect", "expect_{}";
+    Unwrap => "unwrap", "unwrap_{}";
+    UnwrapOr => "unwrap_or", "unwrap_or_{}";
+    UnwrapOrElse => "unwrap_or_else", "unwrap_or_else_{}";
+    Value => "value", "{}";
+    ValueOr => "value_or", "{}_or";
+    ValueOrElse => "value_or_else", "{}_or_else";
+    Ref => "ref", "{}_ref";
+    RefOr => "ref_or", "{}_ref_or";
+    RefOrElse => "ref_or_else", "{}_ref_or_else";
+    Mut => "mut", "{}_mut";
+    MutOr => "mut_or", "{}_mut_or";
+    MutOrElse => "mut_or_else", "{}_mut_or_else";
+    Ok => "ok", "ok_{}";
+    OkOr => "ok_or", "ok_or_{}";
+    OkOrElse => "ok_or_else", "ok_or_else_{}";
+    FieldRef => "field_ref", "{}_ref";
+    FieldMut => "field_mut", "{}_mut";
+    With => "with", "with_{}";
+    Set => "set", "set_{}";
+}
+
+impl Family {
+    /// Families of methods derived for every variant.
+    pub const COMMON: &'static [Family] = &[Family::Is, Family::IsNot, Family::And, Family::Or];
+
+    /// Families of methods derived for tuple-style & struct-like variants.
+    pub const VALUE: &'static [Family] = &[
+        Family::AndThen,
+        Family::Expect,
+        Family::OkOrElse,
+        Family::OkOr,
+        Family::Ok,
+        Family::OrElse,
+        Family::UnwrapOrElse,
+        Family::UnwrapOr,
+        Family::Unwrap,
+        Family::Value,
+        Family::ValueOr,
+        Family::ValueOrElse,
+        Family::Ref,
+        Family::RefOr,
+        Family::RefOrElse,
+        Family::Mut,
+        Family::MutOr,
+        Family::MutOrElse,
+    ];
+
+    /// Families of methods derived for each field of tuple-style & struct-like variants.
+    pub const FIELD: &'static [Family] = &[
+        Family::FieldRef,
+        Family::FieldMut,
+        Family::With,
+        Family::Set,
+    ];
+
+    /// Families of methods derived for unit variants.
+    pub const UNIT: &'static [Family] = &[
+        Family::ValueOr,
+        Family::ValueOrElse,
+        Family::Expect,
+        Family::Unwrap,
+    ];
+}
+
+/// Presets of naming templates, parsed from `#[variantly(style = "...")]`.
+#[derive(FromMeta, Debug, Default, Clone, Copy, PartialEq)]
+pub enum Style {
+    /// The names used by variantly since its inception, such as `{}_ref` or `{}_or`.
+    #[default]
+    #[darling(rename = "standard")]
+    Standard,
+    /// Names following the conversion conventions of the Rust API guidelines, such as `as_{}` or `into_{}`.
+    #[darling(rename = "api_guidelines")]
+    ApiGuidelines,
+}
+
+impl Style {
+    /// The template used to name methods of the given family when not otherwise configured.
+    pub fn template(self, family: Family) -> &'static str {
+        match (self, family) {
+            (Style::ApiGuidelines, Family::Value) => "into_{}",
+            (Style::ApiGuidelines, Family::ValueOr) => "try_into_{}",
+            (Style::ApiGuidelines, Family::ValueOrElse) => "try_into_{}_or_else",
+            (Style::ApiGuidelines, Family::Ref) => "as_{}",
+            (Style::ApiGuidelines, Family::RefOr) => "try_as_{}",
+            (Style::ApiGuidelines, Family::RefOrElse) => "try_as_{}_or_else",
+            (Style::ApiGuidelines, Family::Mut) => "as_{}_mut",
+            (Style::ApiGuidelines, Family::MutOr) => "try_as_{}_mut",
+            (Style::ApiGuidelines, Family::MutOrElse) => "try_as_{}_mut_or_else",
+            (Style::ApiGuidelines, Family::FieldRef) => "as_{}",
+            (Style::ApiGuidelines, Family::FieldMut) => "as_{}_mut",
+            _ => family.default_template(),
+        }
+    }
+}
+
+/// Naming templates for derived methods, parsed from `#[variantly(naming(...))]`.
+/// Each template contains a `{}` placeholder that is replaced by the name of the variant, or for methods concerning a
+/// single field, the name of the variant followed by the name of the field.
+#[derive(Debug, Default)]
+pub struct Naming {
+    templates: HashMap<Family, String>,
+    prefix: String,
+    suffix: String,
+    /// The preset providing templates for families not configured through `naming`.
+    pub style: Style,
+}
+
+impl Naming {
+    /// Construct the name of a derived method of the given family for the variant or field identified by `name`.
+    /// Names that are keywords, such as `match`, are constructed as raw identifiers.
+    pub fn method(&self, family: Family, name: &Ident) -> Ident {
+        let template = self
+            .templates
+            .get(&family)
+            .map(String::as_str)
+            .unwrap_or_else(|| self.style.template(family));
+        let method = format!(
+            "{}{}{}",
+            self.prefix,
+            template.replace("{}", &name.unraw().to_string()),
+            self.suffix
+        );
+        if is_keyword(&method) && !is_reserved(&method) {
+            Ident::new_raw(&method, name.span())
+        } else {
+            Ident::new(&method, name.span())
+        }
+    }
+}
+
+impl FromMeta for Naming {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut naming = Naming::default();
+        let mut errors = vec![];
+
+        for item in items {
+            let (key, value) = match item {
+                NestedMeta::Meta(Meta::NameValue(name_value)) => match &name_value.lit {
+                    Lit::Str(value) => (&name_value.path, value),
+                    lit => {
+                        errors.push(DarlingError::unexpected_lit_type(lit).with_span(lit));
+                        continue;
+                    }
+                },
+                _ => {
+                    errors.push(DarlingError::unsupported_format("non name-value").with_span(item));
+                    continue;
+                }
+            };
+
+            let key_name = key.get_ident().map(Ident::to_string).unwrap_or_default();
+            let result = match key_name.as_str() {
+                "prefix" => validate_affix(value, "{}variant").map(|affix| naming.prefix = affix),
+                "suffix" => validate_affix(value, "variant{}").map(|affix| naming.suffix = affix),
+                key_name => match Family::ALL.iter().find(|family| family.key() == key_name) {
+                    Some(family) => validate_template(value).map(|template| {
+                        naming.templates.insert(*family, template);
+                    }),
+                    None => Err(DarlingError::unknown_field_with_alts(
+                        key_name,
+                        &["prefix", "suffix"]
+                            .iter()
+                            .copied()
+                            .chain(Family::ALL.iter().map(|family| family.key()))
+                            .collect::<Vec<_>>(),
+                    )
+                    .with_span(key)),
+                },
+            };
+            if let Err(err) = result {
+                errors.push(err);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(naming)
+        } else {
+            Err(DarlingError::multiple(errors))
+        }
+    }
+}
+
+/// Validate that a template contains the `{}` placeholder & produces a valid method name.
+fn validate_template(lit: &LitStr) -> darling::Result<String> {
+    let template = lit.value();
+    if !template.contains("{}") {
+        return Err(DarlingError::custom(
+            "naming templates must contain a `{}` placeholder for the variant name",
+        )
+        .with_span(lit));
+    }
+    validate_ident(lit, &template.replace("{}", "variant"))?;
+    Ok(template)
+}
+
+/// Validate that a prefix or suffix produces a valid method name when placed as in the given template.
+/// EX: `{}variant` for a prefix, which must not start with a digit.
+fn validate_affix(lit: &LitStr, template: &str) -> darling::Result<String> {
+    let affix = lit.value();
+    validate_ident(lit, &template.replace("{}", &affix))?;
+    Ok(affix)
+}
+
+/// Methods of well-known traits from `std` & its prelude, along with the trait that declares them & whether they take
+/// `self`. Derived methods of the same name shadow those taking `self` when called with method syntax, & the others
+/// when called through the path of the enum.
+const TRAIT_METHODS: &[(&str, &str, bool)] = &[
+    ("add", "std::ops::Add", true),
+    ("as_mut", "AsMut", true),
+    ("as_ref", "AsRef", true),
+    ("borrow", "std::borrow::Borrow", true),
+    ("borrow_mut", "std::borrow::BorrowMut", true),
+    ("chain", "Iterator", true),
+    ("clone", "Clone", true),
+    ("clone_from", "Clone", true),
+    ("clone_into", "ToOwned", true),
+    ("cloned", "Iterator", true),
+    ("cmp", "Ord", true),
+    ("collect", "Iterator", true),
+    ("count", "Iterator", true),
+    ("default", "Default", false),
+    ("deref", "std::ops::Deref", true),
+    ("deref_mut", "std::ops::DerefMut", true),
+    ("div", "std::ops::Div", true),
+    ("eq", "PartialEq", true),
+    ("extend", "Extend", true),
+    ("filter", "Iterator", true),
+    ("find", "Iterator", true),
+    ("fmt", "std::fmt::Debug", true),
+    ("fold", "Iterator", true),
+    ("from", "From", false),
+    ("from_iter", "FromIterator", false),
+    ("from_str", "std::str::FromStr", false),
+    ("ge", "PartialOrd", true),
+    ("gt", "PartialOrd", true),
+    ("hash", "std::hash::Hash", true),
+    ("index", "std::ops::Index", true),
+    ("index_mut", "std::ops::IndexMut", true),
+    ("into", "Into", true),
+    ("into_iter", "IntoIterator", true),
+    ("last", "Iterator", true),
+    ("le", "PartialOrd", true),
+    ("lt", "PartialOrd", true),
+    ("map", "Iterator", true),
+    ("max", "Ord", true),
+    ("min", "Ord", true),
+    ("mul", "std::ops::Mul", true),
+    ("ne", "PartialEq", true),
+    ("neg", "std::ops::Neg", true),
+    ("next", "Iterator", true),
+    ("not", "std::ops::Not", true),
+    ("partial_cmp", "PartialOrd", true),
+    ("rem", "std::ops::Rem", true),
+    ("sub", "std::ops::Sub", true),
+    ("sum", "Iterator", true),
+    ("to_owned", "ToOwned", true),
+    ("to_string", "ToString", true),
+    ("try_from", "TryFrom", false),
+    ("try_into", "TryInto", true),
+    ("type_id", "std::any::Any", true),
+    ("zip", "Iterator", true),
+];
+
+/// The trait declaring a well-known method of the given name, if any, along with whether the method takes `self`.
+pub fn shadowed_trait(method: &str) -> Option<(&'static str, bool)> {
+    TRAIT_METHODS
+        .iter()
+        .find(|(name, _, _)| *name == method)
+        .map(|(_, trait_path, receiver)| (*trait_path, *receiver))
+}
+
+/// Check whether the given name is a keyword, which may only be used as an identifier in its raw form.
+fn is_keyword(name: &str) -> bool {
+    // Keywords of later editions are missing from the keywords known to `syn`, as `gen` is reserved as of the 2024
+    // edition & the others as of the 2018 edition.
+    matches!(name, "async" | "await" | "dyn" | "try" | "gen")
+        || syn::parse_str::<Ident>(name).is_err()
+}
+
+/// Check whether the given name can not be used as an identifier, even in its raw form.
+pub fn is_reserved(name: &str) -> bool {
+    matches!(name, "self" | "Self" | "super" | "crate")
+}
+
+fn validate_ident(lit: &LitStr, example: &str) -> darling::Result<()> {
+    syn::parse_str::<Ident>(example).map(|_| ()).map_err(|_| {
+        DarlingError::custom(format!(
+            "`{}` would not produce a valid method name",
+            lit.value()
+        ))
+        .with_span(lit)
+    })
+}
+
+/// A list of method families referred to by their keys. EX: `only(is, ref, mut)`
+#[derive(Debug, Default, Clone)]
+pub struct FamilyList(pub Vec<Family>);
+
+impl FromMeta for FamilyList {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut families = vec![];
+        let mut errors = vec![];
+
+        for item in items {
+            let family = match item {
+                NestedMeta::Meta(Meta::Path(path)) => {
+                    let key_name = path.get_ident().map(Ident::to_string).unwrap_or_default();
+                    Family::ALL
+                        .iter()
+                        .find(|family| family.key() == key_name)
+                        .ok_or_else(|| {
+                            DarlingError::custom(format!(
+                                "Unknown method family: `{}`.{}",
+                                key_name,
+                                did_you_mean(
+                                    &key_name,
+                                    Family::ALL.iter().map(|family| family.key())
+                                )
+                            ))
+                            .with_span(path)
+                        })
+                }
+                _ => Err(DarlingError::unsupported_format("non-path").with_span(item)),
+            };
+            match family {
+                Ok(family) => families.push(*family),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(FamilyList(families))
+        } else {
+            Err(DarlingError::multiple(errors))
+        }
+    }
+}
+
+/// Restrict the families of derived methods, parsed from `#[variantly(only(...))]` or `#[variantly(exclude(...))]`.
+#[derive(Debug, Default, Clone)]
+pub struct Selection {
+    pub only: Option<FamilyList>,
+    pub exclude: Option<FamilyList>,
+}
+
+impl Selection {
+    /// Check whether methods of the given family are selected.
+    pub fn includes(&self, family: Family) -> bool {
+        let listed = |list: &Option<FamilyList>| list.as_ref().map(|list| list.0.contains(&family));
+        listed(&self.only).unwrap_or(true) && !listed(&self.exclude).unwrap_or(false)
+    }
+
+    /// Validate that at most one of `only` & `exclude` was used.
+    pub fn validate(&self, span: Span) -> syn::Result<()> {
+        match (&self.only, &self.exclude) {
+            (Some(_), Some(_)) => Err(syn::Error::new(
+                span,
+                "`only` & `exclude` cannot be used together on the same item.",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
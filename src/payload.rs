@@ -0,0 +1,270 @@
+//! Generate named companion structs for struct-like variants.
+use crate::{
+    idents::mentions,
+    input::{Codegen, VariantParsed},
+};
+use darling::{
+    usage::{CollectLifetimes, CollectTypeParams, GenericsExt, Purpose},
+    util::Override,
+};
+use proc_macro2::{Group, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Attribute, GenericParam, Generics, Ident, ItemEnum, Lifetime, Type};
+
+/// The owned, borrowed & mutably borrowed payload structs requested for a single struct-like variant through
+/// `#[variantly(payload_struct)]`.
+pub struct Payload {
+    pub ident: Ident,
+    pub ref_ident: Ident,
+    pub mut_ident: Ident,
+    /// Names of the variant fields, in declaration order.
+    names: Vec<Ident>,
+    /// Types of the variant fields, in which `Self` refers to the enum rather than the payload struct.
+    types: Vec<Type>,
+    /// The subset of the enum's generics used by the fields of the variant.
+    generics: Generics,
+    /// Lifetime of the references held by the borrowed structs.
+    lifetime: Lifetime,
+}
+
+impl Payload {
+    /// Resolve the payload structs of the given variant, if they were requested.
+    pub fn new(variant: &VariantParsed, item_enum: &ItemEnum) -> Option<Self> {
+        let ident = match variant.payload_struct.as_ref()? {
+            Override::Explicit(ident) => ident.clone(),
+            Override::Inherit => format_ident!("{}{}Payload", item_enum.ident, variant.ident),
+        };
+
+        let enum_name = &item_enum.ident;
+        let (_, ty_generics, _) = item_enum.generics.split_for_impl();
+        let enum_ty = syn::parse_quote!(#enum_name #ty_generics);
+        let types: Vec<Type> = variant
+            .fields
+            .fields
+            .iter()
+            .map(|field| resolve_self(&field.ty, &enum_ty))
+            .collect();
+
+        Some(Payload {
+            ref_ident: format_ident!("{}Ref", ident),
+            mut_ident: format_ident!("{}Mut", ident),
+            ident,
+            names: variant
+                .fields
+                .fields
+                .iter()
+                .filter_map(|field| field.ident.clone())
+                .collect(),
+            generics: used_generics(&types, &item_enum.generics),
+            types,
+            lifetime: Lifetime::new("'variantly", Span::call_site()),
+        })
+    }
+
+    /// The owned payload type. EX: `ColorCustomPayload<T>`
+    pub fn ty(&self) -> TokenStream2 {
+        let ident = &self.ident;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        quote! { #ident#ty_generics }
+    }
+
+    /// The borrowed payload type, tied to the lifetime of `&self`. EX: `ColorCustomPayloadRef<'_, T>`
+    pub fn ref_ty(&self) -> TokenStream2 {
+        self.borrowed_ty(&self.ref_ident)
+    }
+
+    /// The mutably borrowed payload type, tied to the lifetime of `&mut self`. EX: `ColorCustomPayloadMut<'_, T>`
+    pub fn mut_ty(&self) -> TokenStream2 {
+        self.borrowed_ty(&self.mut_ident)
+    }
+
+    /// Used for both pattern matching and constructing the owned payload.
+    /// EX: `ColorCustomPayload { name: some_variable_1, alpha: some_variable_2 }`
+    pub fn value(&self, vars: &[Ident]) -> TokenStream2 {
+        Self::bind(&self.ident, &self.names, vars)
+    }
+
+    /// Construct the borrowed payload from variables bound by reference.
+    pub fn ref_value(&self, vars: &[Ident]) -> TokenStream2 {
+        Self::bind(&self.ref_ident, &self.names, vars)
+    }
+
+    /// Construct the mutably borrowed payload from variables bound by mutable reference.
+    pub fn mut_value(&self, vars: &[Ident]) -> TokenStream2 {
+        Self::bind(&self.mut_ident, &self.names, vars)
+    }
+
+    /// Declare all payload structs as well as a `From` impl for converting the owned payload back into the enum.
+    pub fn definitions(
+        &self,
+        variant: &VariantParsed,
+        item_enum: &ItemEnum,
+        codegen: &Codegen,
+    ) -> TokenStream2 {
+        let Payload {
+            ident,
+            ref_ident,
+            mut_ident,
+            names,
+            types,
+            lifetime,
+            ..
+        } = self;
+        let vis = &item_enum.vis;
+        let enum_name = &item_enum.ident;
+        let variant_ident = &variant.ident;
+
+        let fields = &variant.fields.fields;
+        // Carry over the docs of each field.
+        let docs: Vec<Vec<&Attribute>> = fields
+            .iter()
+            .map(|field| {
+                field
+                    .attrs
+                    .iter()
+                    .filter(|attr| attr.path.is_ident("doc"))
+                    .collect()
+            })
+            .collect();
+
+        let params = &self.generics.params;
+        let where_clause = &self.generics.where_clause;
+        let payload_ty = self.ty();
+        let automatically_derived = codegen
+            .automatically_derived
+            .then(|| quote!(#[automatically_derived]));
+        let inline = codegen.inline.then(|| quote!(#[inline]));
+        let (impl_generics, ty_generics, enum_where_clause) = item_enum.generics.split_for_impl();
+
+        let doc = format!(
+            "The fields of the [`{}::{}`] variant.",
+            enum_name, variant_ident
+        );
+        let ref_doc = format!(
+            "References to the fields of the [`{}::{}`] variant.",
+            enum_name, variant_ident
+        );
+        let mut_doc = format!(
+            "Mutable references to the fields of the [`{}::{}`] variant.",
+            enum_name, variant_ident
+        );
+
+        quote! {
+            #[doc = #doc]
+            #vis struct #ident<#params> #where_clause {
+                #( #(#docs)* #vis #names: #types, )*
+            }
+
+            #[doc = #ref_doc]
+            #vis struct #ref_ident<#lifetime, #params> #where_clause {
+                #( #(#docs)* #vis #names: &#lifetime #types, )*
+            }
+
+            #[doc = #mut_doc]
+            #vis struct #mut_ident<#lifetime, #params> #where_clause {
+                #( #(#docs)* #vis #names: &#lifetime mut #types, )*
+            }
+
+            #automatically_derived
+            impl #impl_generics ::core::convert::From<#payload_ty> for #enum_name #ty_generics #enum_where_clause {
+                #inline
+                fn from(payload: #payload_ty) -> Self {
+                    #enum_name::#variant_ident { #( #names: payload.#names ),* }
+                }
+            }
+        }
+    }
+
+    fn borrowed_ty(&self, ident: &Ident) -> TokenStream2 {
+        let args = self.generics.params.iter().map(|param| match param {
+            GenericParam::Lifetime(def) => def.lifetime.to_token_stream(),
+            GenericParam::Type(param) => param.ident.to_token_stream(),
+            GenericParam::Const(param) => param.ident.to_token_stream(),
+        });
+        quote! { #ident<'_, #( #args ),*> }
+    }
+
+    fn bind(ident: &Ident, names: &[Ident], vars: &[Ident]) -> TokenStream2 {
+        quote! { #ident { #( #names: #vars ),* } }
+    }
+}
+
+/// Reduce the enum's generics to those used by the fields of the given variant, as structs may not declare unused
+/// type or lifetime parameters. Where predicates that mention a removed parameter are removed along with it.
+fn used_generics(types: &[Type], generics: &Generics) -> Generics {
+    let used_type_params = types
+        .iter()
+        .collect_type_params_cloned(&Purpose::Declare.into(), &generics.declared_type_params());
+    let used_lifetimes = types
+        .iter()
+        .collect_lifetimes_cloned(&Purpose::Declare.into(), &generics.declared_lifetimes());
+
+    let is_used = |param: &GenericParam| match param {
+        GenericParam::Type(param) => used_type_params.contains(&param.ident),
+        GenericParam::Lifetime(def) => used_lifetimes.contains(&def.lifetime),
+        // Unused const parameters are permitted.
+        GenericParam::Const(_) => true,
+    };
+    let unused: Vec<Ident> = generics
+        .params
+        .iter()
+        .filter(|param| !is_used(param))
+        .map(|param| match param {
+            GenericParam::Lifetime(def) => def.lifetime.ident.clone(),
+            GenericParam::Type(param) => param.ident.clone(),
+            GenericParam::Const(param) => param.ident.clone(),
+        })
+        .collect();
+
+    let mut used = generics.clone();
+    used.params = generics
+        .params
+        .iter()
+        .filter(|param| is_used(param))
+        .cloned()
+        .collect();
+    used.where_clause = generics.where_clause.as_ref().and_then(|where_clause| {
+        let mut where_clause = where_clause.clone();
+        where_clause.predicates = where_clause
+            .predicates
+            .into_iter()
+            .filter(|predicate| !mentions(predicate.to_token_stream(), &unused))
+            .collect();
+        if where_clause.predicates.is_empty() {
+            None
+        } else {
+            Some(where_clause)
+        }
+    });
+    used
+}
+
+/// Replace each `Self` within the given type with the type of the enum, as `Self` would otherwise refer to the payload
+/// struct the type is declared in. EX: `Box<List<T>>` for `Box<Self>`, or `<List<T>>::Item` for `Self::Item`
+fn resolve_self(ty: &Type, enum_ty: &Type) -> Type {
+    fn replace(tokens: TokenStream2, enum_ty: &Type) -> TokenStream2 {
+        let mut tokens = tokens.into_iter().peekable();
+        let mut replaced = TokenStream2::new();
+        while let Some(token) = tokens.next() {
+            match token {
+                TokenTree::Ident(ident) if ident == "Self" => match tokens.peek() {
+                    // Paths into the enum type must be qualified, as generic arguments may not precede `::`.
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => {
+                        replaced.extend(quote!(<#enum_ty>))
+                    }
+                    _ => replaced.extend(enum_ty.to_token_stream()),
+                },
+                TokenTree::Group(group) => {
+                    let mut resolved =
+                        Group::new(group.delimiter(), replace(group.stream(), enum_ty));
+                    resolved.set_span(group.span());
+                    replaced.extend(std::iter::once(TokenTree::Group(resolved)));
+                }
+                token => replaced.extend(std::iter::once(token)),
+            }
+        }
+        replaced
+    }
+
+    syn::parse2(replace(ty.to_token_stream(), enum_ty)).unwrap_or_else(|_| ty.clone())
+}
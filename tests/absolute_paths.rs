@@ -0,0 +1,40 @@
+#![allow(dead_code, non_camel_case_types)]
+
+// Test compilation while local modules & types shadow `std`, `core` & primitive types.
+mod std {}
+mod core {}
+struct bool;
+struct str;
+
+mod facade {
+    pub(crate) use ::variantly;
+}
+
+use variantly::Variantly;
+
+#[derive(Variantly, Debug, PartialEq)]
+#[variantly(crate = "facade::variantly")]
+enum Shape {
+    Circle(u32),
+    Rectangle { width: u32, height: u32 },
+    Empty,
+}
+
+#[test]
+fn derived_methods() {
+    let mut rectangle = Shape::Rectangle {
+        width: 1,
+        height: 2,
+    };
+    assert!(Shape::Circle(1).is_circle());
+    assert_eq!(Shape::Circle(1).circle_or(()), Ok(1));
+    assert_eq!(Shape::Empty.empty_or_else(|| ()), Ok(()));
+    assert_eq!(rectangle.set_rectangle_width(3), Some(1));
+    assert_eq!(rectangle.rectangle_height_ref(), Some(&2));
+}
+
+#[test]
+#[should_panic(expected = "called `Shape::unwrap_circle()` on a `Shape::Empty` value")]
+fn panics() {
+    Shape::Empty.unwrap_circle();
+}
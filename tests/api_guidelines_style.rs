@@ -0,0 +1,52 @@
+use variantly::Variantly;
+
+#[derive(Variantly, Debug, PartialEq)]
+#[variantly(style = "api_guidelines")]
+enum Color {
+    Rgb(u8, u8, u8),
+    Named { name: String },
+    Unit,
+}
+
+#[derive(Variantly, Debug, PartialEq)]
+#[variantly(style = "api_guidelines", naming(ref = "view_{}"))]
+enum Overridden {
+    Int(u128),
+}
+
+#[test]
+fn conversions() {
+    // Match
+    assert!(Color::Rgb(1, 2, 3).is_rgb());
+    assert_eq!(Color::Rgb(1, 2, 3).as_rgb(), Some((&1, &2, &3)));
+    assert_eq!(
+        Color::Rgb(1, 2, 3).as_rgb_mut(),
+        Some((&mut 1, &mut 2, &mut 3))
+    );
+    assert_eq!(Color::Rgb(1, 2, 3).into_rgb(), Some((1, 2, 3)));
+    assert_eq!(Color::Rgb(1, 2, 3).try_into_rgb("ERR"), Ok((1, 2, 3)));
+    assert_eq!(Color::Rgb(1, 2, 3).try_as_rgb("ERR"), Ok((&1, &2, &3)));
+
+    // Non-Match
+    assert!(Color::Unit.is_not_rgb());
+    assert_eq!(Color::Unit.as_rgb(), None);
+    assert_eq!(Color::Unit.into_rgb(), None);
+    assert_eq!(Color::Unit.try_into_rgb_or_else(|| "ERR"), Err("ERR"));
+    assert_eq!(Color::Rgb(1, 2, 3).try_into_unit("ERR"), Err("ERR"));
+}
+
+#[test]
+fn fields() {
+    let mut color = Color::Named {
+        name: "teal".into(),
+    };
+    assert_eq!(color.as_named_name(), Some(&"teal".into()));
+    color.as_named_name_mut().unwrap().push_str("-ish");
+    assert_eq!(color.into_named(), Some("teal-ish".into()));
+}
+
+#[test]
+fn naming_overrides_style() {
+    assert_eq!(Overridden::Int(123).view_int(), Some(&123));
+    assert_eq!(Overridden::Int(123).into_int(), Some(123));
+}
@@ -0,0 +1,32 @@
+use variantly::Variantly;
+
+#[derive(Variantly, Debug, PartialEq)]
+#[variantly(rename_all = "snake_case", only(is, value, unwrap))]
+#[variantly(allow_shadowing, vis = "pub(crate)")]
+enum Message {
+    #[variantly(rename = "text_message")]
+    #[variantly(exclude(unwrap))]
+    Text(String),
+    Ping {
+        /// Other attributes on fields are left untouched.
+        #[allow(unused)]
+        sequence: u32,
+    },
+    #[variantly(skip)]
+    Close,
+}
+
+#[test]
+fn keys_may_be_split_across_attributes() {
+    let text = Message::Text("hello".into());
+    assert!(text.is_text_message());
+    assert_eq!(text.text_message(), Some("hello".to_string()));
+}
+
+#[test]
+fn field_attributes_are_ignored() {
+    let ping = Message::Ping { sequence: 1 };
+    assert!(ping.is_ping());
+    assert_eq!(ping.unwrap_ping(), 1);
+    assert_eq!(Message::Close.ping(), None);
+}
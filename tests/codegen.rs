@@ -0,0 +1,93 @@
+#![allow(dead_code)]
+#![deny(unused_must_use)]
+
+use std::{
+    panic::{self, Location},
+    sync::Mutex,
+};
+use variantly::Variantly;
+
+#[derive(Variantly, Debug)]
+enum Color {
+    Rgb(u8, u8, u8),
+    Grey(u8),
+    Unknown,
+}
+
+#[derive(Variantly, Debug)]
+#[variantly(codegen(
+    inline = false,
+    must_use = false,
+    track_caller = false,
+    cold = false,
+    automatically_derived = false
+))]
+enum Plain {
+    One(u8),
+    Two,
+}
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// Run the given closure, returning the line of the reported panic location.
+fn panic_line<F: FnOnce() + panic::UnwindSafe>(f: F) -> Option<u32> {
+    let _guard = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let line = std::sync::Arc::new(Mutex::new(None));
+    let hook_line = line.clone();
+    panic::set_hook(Box::new(move |info| {
+        *hook_line.lock().unwrap() = info.location().map(Location::line);
+    }));
+    assert!(panic::catch_unwind(f).is_err());
+    let _ = panic::take_hook();
+    let line = *line.lock().unwrap();
+    line
+}
+
+#[test]
+fn track_caller() {
+    let line = Location::caller().line();
+    assert_eq!(
+        panic_line(|| {
+            Color::Grey(1).unwrap_rgb();
+        }),
+        Some(line + 3)
+    );
+    assert_eq!(
+        panic_line(|| {
+            Color::Grey(1).expect_rgb("Not RGB");
+        }),
+        Some(line + 9)
+    );
+    assert_eq!(
+        panic_line(|| {
+            Color::Grey(1).unwrap_unknown();
+        }),
+        Some(line + 15)
+    );
+    assert_eq!(
+        panic_line(|| {
+            Color::Grey(1).expect_unknown("Not unknown");
+        }),
+        Some(line + 21)
+    );
+}
+
+#[test]
+fn track_caller_disabled() {
+    let line = Location::caller().line();
+    assert_ne!(
+        panic_line(|| {
+            Plain::Two.unwrap_one();
+        }),
+        Some(line + 3)
+    );
+}
+
+#[test]
+fn disabled() {
+    // Ignoring results is permitted without `#[must_use]`.
+    Plain::One(1).is_one();
+    Plain::One(1).one_ref();
+    assert!(Plain::One(1).is_one());
+    assert_eq!(Plain::One(1).one_ref(), Some(&1));
+}
@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+
+use variantly::Variantly;
+
+#[derive(Variantly)]
+enum Mode {
+    Fast,
+    Slow(u8),
+    Custom { speed: u32, label: &'static str },
+}
+
+const DEFAULT_MODE: Mode = Mode::Fast;
+const SLOW_MODE: Mode = Mode::Slow(3);
+const CUSTOM_MODE: Mode = Mode::Custom {
+    speed: 42,
+    label: "custom",
+};
+
+const _: () = assert!(DEFAULT_MODE.is_fast());
+const _: () = assert!(DEFAULT_MODE.is_not_slow());
+
+const SLOWNESS: u8 = match SLOW_MODE.slow_ref() {
+    Some(slowness) => *slowness,
+    None => 0,
+};
+
+const SPEED: u32 = match CUSTOM_MODE.custom_speed_ref() {
+    Some(speed) => *speed,
+    None => 0,
+};
+
+/// Predicates may be used within const tables.
+const FAST_MODES: [bool; 3] = [
+    DEFAULT_MODE.is_fast(),
+    SLOW_MODE.is_fast(),
+    CUSTOM_MODE.is_fast(),
+];
+
+#[derive(Variantly)]
+#[variantly(const_fn = false)]
+enum NonConst {
+    One(u8),
+}
+
+#[test]
+fn const_predicates() {
+    assert_eq!(FAST_MODES, [true, false, false]);
+}
+
+#[test]
+fn const_refs() {
+    assert_eq!(SLOWNESS, 3);
+    assert_eq!(SPEED, 42);
+}
+
+#[test]
+fn opt_out() {
+    assert!(NonConst::One(1).is_one());
+}
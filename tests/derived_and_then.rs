@@ -1,7 +1,7 @@
 mod helper;
 use helper::{
     TestEnum,
-    TestEnum::{Int, Unit},
+    TestEnum::{Int, StructLike, Unit},
 };
 
 #[test]
@@ -30,3 +30,35 @@ fn multi_value_tuple() {
     // Non-Match
     assert!(Unit.and_then_tuple(and_then).is_unit());
 }
+
+#[test]
+fn single_value_struct() {
+    let and_then = |val| val + 100;
+
+    // Match
+    assert_eq!(
+        StructLike { value: 123 }
+            .and_then_struct_like(and_then)
+            .unwrap_struct_like(),
+        223
+    );
+
+    // Non-Match
+    assert!(Unit.and_then_struct_like(and_then).is_unit());
+}
+
+#[test]
+fn multi_value_struct() {
+    let and_then = |(text, value)| (format!("{}{}", text, text), value + value);
+
+    // Match
+    assert_eq!(
+        TestEnum::new_record(123)
+            .and_then_record(and_then)
+            .unwrap_record(),
+        ("123123".into(), 246)
+    );
+
+    // Non-Match
+    assert!(Unit.and_then_record(and_then).is_unit());
+}
@@ -1,7 +1,7 @@
 mod helper;
 use helper::{
     TestEnum,
-    TestEnum::{Int, Unit},
+    TestEnum::{Int, OtherUnit, StructLike, Unit},
 };
 
 #[test]
@@ -10,7 +10,7 @@ fn single_value_tuple() {
 }
 
 #[test]
-#[should_panic(expected = "This should have been an int")]
+#[should_panic(expected = "This should have been an int: found a `TestEnum::Unit` value")]
 fn single_value_tuple_panic() {
     Unit.expect_int("This should have been an int");
 }
@@ -24,7 +24,46 @@ fn multi_value_tuple() {
 }
 
 #[test]
-#[should_panic(expected = "This should have been a tuple")]
+#[should_panic(expected = "This should have been a tuple: found a `TestEnum::Unit` value")]
 fn multi_value_tuple_panic() {
     Unit.expect_tuple("This should have been a tuple");
 }
+
+#[test]
+fn single_value_struct() {
+    assert_eq!(
+        StructLike { value: 123 }.expect_struct_like("This should have been struct like"),
+        123
+    );
+}
+
+#[test]
+#[should_panic(expected = "This should have been struct like: found a `TestEnum::Unit` value")]
+fn single_value_struct_panic() {
+    Unit.expect_struct_like("This should have been struct like");
+}
+
+#[test]
+fn multi_value_struct() {
+    assert_eq!(
+        TestEnum::new_record(123).expect_record("This should have been a record"),
+        ("123".into(), 123)
+    );
+}
+
+#[test]
+#[should_panic(expected = "This should have been a record: found a `TestEnum::Unit` value")]
+fn multi_value_struct_panic() {
+    Unit.expect_record("This should have been a record");
+}
+
+#[test]
+fn unit() {
+    Unit.expect_unit("This should have been a unit");
+}
+
+#[test]
+#[should_panic(expected = "This should have been a unit: found a `TestEnum::OtherUnit` value")]
+fn unit_panic() {
+    OtherUnit.expect_unit("This should have been a unit");
+}
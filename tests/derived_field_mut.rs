@@ -0,0 +1,53 @@
+mod helper;
+use helper::{
+    TestEnum,
+    TestEnum::{Int, StructLike, Unit},
+};
+
+#[test]
+fn single_value_tuple() {
+    // Match
+    let mut int = Int(123);
+    *int.int_0_mut().unwrap() += 1;
+    assert_eq!(int.unwrap_int(), 124);
+
+    // Non-Match
+    assert_eq!(Unit.int_0_mut(), None);
+}
+
+#[test]
+fn multi_value_tuple() {
+    // Match
+    let mut tuple = TestEnum::new_tuple(123);
+    tuple.tuple_0_mut().unwrap().push('4');
+    *tuple.tuple_1_mut().unwrap() += 1;
+    assert_eq!(tuple.unwrap_tuple(), ("1234".into(), 124));
+
+    // Non-Match
+    assert_eq!(Unit.tuple_0_mut(), None);
+    assert_eq!(Unit.tuple_1_mut(), None);
+}
+
+#[test]
+fn single_value_struct() {
+    // Match
+    let mut struct_like = StructLike { value: 123 };
+    *struct_like.struct_like_value_mut().unwrap() += 1;
+    assert_eq!(struct_like.unwrap_struct_like(), 124);
+
+    // Non-Match
+    assert_eq!(Unit.struct_like_value_mut(), None);
+}
+
+#[test]
+fn multi_value_struct() {
+    // Match
+    let mut record = TestEnum::new_record(123);
+    record.record_text_mut().unwrap().push('4');
+    *record.record_value_mut().unwrap() += 1;
+    assert_eq!(record.unwrap_record(), ("1234".into(), 124));
+
+    // Non-Match
+    assert_eq!(Unit.record_text_mut(), None);
+    assert_eq!(Unit.record_value_mut(), None);
+}
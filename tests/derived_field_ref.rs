@@ -0,0 +1,71 @@
+mod helper;
+use helper::{
+    TestEnum,
+    TestEnum::{Int, StructLike, Unit},
+};
+
+#[test]
+fn single_value_tuple() {
+    // Match
+    assert_eq!(Int(123).int_0_ref(), Some(&123));
+
+    // Non-Match
+    assert_eq!(Unit.int_0_ref(), None);
+}
+
+#[test]
+fn multi_value_tuple() {
+    // Match
+    assert_eq!(TestEnum::new_tuple(123).tuple_0_ref(), Some(&"123".into()));
+    assert_eq!(TestEnum::new_tuple(123).tuple_1_ref(), Some(&123));
+
+    // Non-Match
+    assert_eq!(Unit.tuple_0_ref(), None);
+    assert_eq!(Unit.tuple_1_ref(), None);
+}
+
+#[test]
+fn single_value_struct() {
+    // Match
+    assert_eq!(
+        StructLike { value: 123 }.struct_like_value_ref(),
+        Some(&123)
+    );
+
+    // Non-Match
+    assert_eq!(Unit.struct_like_value_ref(), None);
+}
+
+#[test]
+fn multi_value_struct() {
+    // Match
+    assert_eq!(
+        TestEnum::new_record(123).record_text_ref(),
+        Some(&"123".into())
+    );
+    assert_eq!(TestEnum::new_record(123).record_value_ref(), Some(&123));
+
+    // Non-Match
+    assert_eq!(Unit.record_text_ref(), None);
+    assert_eq!(Unit.record_value_ref(), None);
+}
+
+#[deny(non_snake_case)]
+mod underscored_fields {
+    use variantly::Variantly;
+
+    #[derive(Variantly)]
+    pub enum Receiver {
+        Method { self_: u8, _private: u8 },
+    }
+
+    #[test]
+    fn underscores_are_trimmed() {
+        let method = Receiver::Method {
+            self_: 1,
+            _private: 2,
+        };
+        assert_eq!(method.method_self_ref(), Some(&1));
+        assert_eq!(method.method_private_ref(), Some(&2));
+    }
+}
@@ -1,7 +1,7 @@
 mod helper;
 use helper::{
     TestEnum,
-    TestEnum::{Int, Unit},
+    TestEnum::{Int, StructLike, Unit},
 };
 
 #[test]
@@ -24,3 +24,24 @@ fn multi_value_tuple() {
     // Non-Match
     assert_eq!(Unit.tuple_mut(), None);
 }
+
+#[test]
+fn single_value_struct() {
+    // Match
+    assert_eq!(StructLike { value: 123 }.struct_like_mut(), Some(&mut 123));
+
+    // Non-Match
+    assert_eq!(Unit.struct_like_mut(), None);
+}
+
+#[test]
+fn multi_value_struct() {
+    // Match
+    assert_eq!(
+        TestEnum::new_record(123).record_mut(),
+        Some((&mut "123".into(), &mut 123))
+    );
+
+    // Non-Match
+    assert_eq!(Unit.record_mut(), None);
+}
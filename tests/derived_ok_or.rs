@@ -1,7 +1,7 @@
 mod helper;
 use helper::{
     TestEnum,
-    TestEnum::{Int, Unit},
+    TestEnum::{Int, OtherUnit, StructLike, Unit},
 };
 
 #[test]
@@ -47,3 +47,36 @@ fn multi_value_tuple() {
     // Non-Match
     assert_eq!(Unit.tuple_or("ERR").unwrap_err(), "ERR");
 }
+
+#[test]
+fn single_value_struct() {
+    // Match
+    assert_eq!(
+        StructLike { value: 123 }.struct_like_or("ERR").unwrap(),
+        123
+    );
+
+    // Non-Match
+    assert_eq!(Unit.struct_like_or("ERR").unwrap_err(), "ERR");
+}
+
+#[test]
+fn multi_value_struct() {
+    // Match
+    assert_eq!(
+        TestEnum::new_record(123).record_or("ERR").unwrap(),
+        ("123".into(), 123)
+    );
+
+    // Non-Match
+    assert_eq!(Unit.record_or("ERR").unwrap_err(), "ERR");
+}
+
+#[test]
+fn unit() {
+    // Match
+    assert_eq!(Unit.unit_or("ERR"), Ok(()));
+
+    // Non-Match
+    assert_eq!(OtherUnit.unit_or("ERR"), Err("ERR"));
+}
@@ -1,7 +1,7 @@
 mod helper;
 use helper::{
     TestEnum,
-    TestEnum::{Int, Unit},
+    TestEnum::{Int, OtherUnit, StructLike, Unit},
 };
 
 #[test]
@@ -47,3 +47,38 @@ fn multi_value_tuple() {
     // Non-Match
     assert_eq!(Unit.tuple_or_else(|| "ERR").unwrap_err(), "ERR");
 }
+
+#[test]
+fn single_value_struct() {
+    // Match
+    assert_eq!(
+        StructLike { value: 123 }
+            .struct_like_or_else(|| "ERR")
+            .unwrap(),
+        123
+    );
+
+    // Non-Match
+    assert_eq!(Unit.struct_like_or_else(|| "ERR").unwrap_err(), "ERR");
+}
+
+#[test]
+fn multi_value_struct() {
+    // Match
+    assert_eq!(
+        TestEnum::new_record(123).record_or_else(|| "ERR").unwrap(),
+        ("123".into(), 123)
+    );
+
+    // Non-Match
+    assert_eq!(Unit.record_or_else(|| "ERR").unwrap_err(), "ERR");
+}
+
+#[test]
+fn unit() {
+    // Match
+    assert_eq!(Unit.unit_or_else(|| "ERR"), Ok(()));
+
+    // Non-Match
+    assert_eq!(OtherUnit.unit_or_else(|| "ERR"), Err("ERR"));
+}
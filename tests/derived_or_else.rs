@@ -1,7 +1,7 @@
 mod helper;
 use helper::{
     TestEnum,
-    TestEnum::{Int, Unit},
+    TestEnum::{Int, StructLike, Unit},
 };
 
 #[test]
@@ -27,3 +27,34 @@ fn multi_value_tuple() {
         ("456".into(), 456)
     );
 }
+
+#[test]
+fn single_value_struct() {
+    // Match
+    assert_eq!(
+        StructLike { value: 123 }
+            .or_else_struct_like(|| 456)
+            .unwrap_struct_like(),
+        123
+    );
+
+    // Non-Match
+    assert_eq!(Unit.or_else_struct_like(|| 456).unwrap_struct_like(), 456);
+}
+
+#[test]
+fn multi_value_struct() {
+    // Match
+    assert_eq!(
+        TestEnum::new_record(123)
+            .or_else_record(|| ("456".into(), 456))
+            .unwrap_record(),
+        ("123".into(), 123)
+    );
+
+    // Non-Match
+    assert_eq!(
+        Unit.or_else_record(|| ("456".into(), 456)).unwrap_record(),
+        ("456".into(), 456)
+    );
+}
@@ -1,7 +1,7 @@
 mod helper;
 use helper::{
     TestEnum,
-    TestEnum::{Int, Unit},
+    TestEnum::{Int, StructLike, Unit},
 };
 
 #[test]
@@ -24,3 +24,24 @@ fn multi_value_tuple() {
     // Non-Match
     assert_eq!(Unit.tuple_ref(), None);
 }
+
+#[test]
+fn single_value_struct() {
+    // Match
+    assert_eq!(StructLike { value: 123 }.struct_like_ref(), Some(&123));
+
+    // Non-Match
+    assert_eq!(Unit.struct_like_ref(), None);
+}
+
+#[test]
+fn multi_value_struct() {
+    // Match
+    assert_eq!(
+        TestEnum::new_record(123).record_ref(),
+        Some((&"123".into(), &123))
+    );
+
+    // Non-Match
+    assert_eq!(Unit.record_ref(), None);
+}
@@ -1,7 +1,7 @@
 mod helper;
 use helper::{
     TestEnum,
-    TestEnum::{Int, Unit},
+    TestEnum::{Int, OtherUnit, StructLike, Unit},
 };
 
 #[test]
@@ -10,7 +10,7 @@ fn single_value_tuple() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "called `TestEnum::unwrap_int()` on a `TestEnum::Unit` value")]
 fn single_value_tuple_panic() {
     Unit.unwrap_int();
 }
@@ -21,7 +21,43 @@ fn multi_value_tuple() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "called `TestEnum::unwrap_tuple()` on a `TestEnum::Unit` value")]
 fn multi_value_tuple_panic() {
     Unit.unwrap_tuple();
 }
+
+#[test]
+fn single_value_struct() {
+    assert_eq!(StructLike { value: 123 }.unwrap_struct_like(), 123);
+}
+
+#[test]
+#[should_panic(expected = "called `TestEnum::unwrap_struct_like()` on a `TestEnum::Unit` value")]
+fn single_value_struct_panic() {
+    Unit.unwrap_struct_like();
+}
+
+#[test]
+fn multi_value_struct() {
+    assert_eq!(
+        TestEnum::new_record(123).unwrap_record(),
+        ("123".into(), 123)
+    );
+}
+
+#[test]
+#[should_panic(expected = "called `TestEnum::unwrap_record()` on a `TestEnum::Unit` value")]
+fn multi_value_struct_panic() {
+    Unit.unwrap_record();
+}
+
+#[test]
+fn unit() {
+    Unit.unwrap_unit();
+}
+
+#[test]
+#[should_panic(expected = "called `TestEnum::unwrap_unit()` on a `TestEnum::OtherUnit` value")]
+fn unit_panic() {
+    OtherUnit.unwrap_unit();
+}
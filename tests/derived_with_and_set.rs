@@ -0,0 +1,77 @@
+mod helper;
+use helper::{
+    TestEnum,
+    TestEnum::{Int, StructLike, Unit},
+};
+
+#[test]
+fn single_value_tuple() {
+    // Match
+    assert_eq!(Int(123).with_int_0(456).unwrap_int(), 456);
+
+    let mut int = Int(123);
+    assert_eq!(int.set_int_0(456), Some(123));
+    assert_eq!(int.unwrap_int(), 456);
+
+    // Non-Match
+    assert!(Unit.with_int_0(456).is_unit());
+
+    let mut unit = Unit;
+    assert_eq!(unit.set_int_0(456), None);
+    assert!(unit.is_unit());
+}
+
+#[test]
+fn multi_value_tuple() {
+    // Match
+    assert_eq!(
+        TestEnum::new_tuple(123).with_tuple_1(456).unwrap_tuple(),
+        ("123".into(), 456)
+    );
+
+    let mut tuple = TestEnum::new_tuple(123);
+    assert_eq!(tuple.set_tuple_0("456".into()), Some("123".into()));
+    assert_eq!(tuple.unwrap_tuple(), ("456".into(), 123));
+
+    // Non-Match
+    assert!(Unit.with_tuple_1(456).is_unit());
+    assert_eq!(Unit.set_tuple_0("456".into()), None);
+}
+
+#[test]
+fn single_value_struct() {
+    // Match
+    assert_eq!(
+        StructLike { value: 123 }
+            .with_struct_like_value(456)
+            .unwrap_struct_like(),
+        456
+    );
+
+    let mut struct_like = StructLike { value: 123 };
+    assert_eq!(struct_like.set_struct_like_value(456), Some(123));
+    assert_eq!(struct_like.unwrap_struct_like(), 456);
+
+    // Non-Match
+    assert!(Unit.with_struct_like_value(456).is_unit());
+    assert_eq!(Unit.set_struct_like_value(456), None);
+}
+
+#[test]
+fn multi_value_struct() {
+    // Match
+    assert_eq!(
+        TestEnum::new_record(123)
+            .with_record_value(456)
+            .unwrap_record(),
+        ("123".into(), 456)
+    );
+
+    let mut record = TestEnum::new_record(123);
+    assert_eq!(record.set_record_text("456".into()), Some("123".into()));
+    assert_eq!(record.unwrap_record(), ("456".into(), 123));
+
+    // Non-Match
+    assert!(Unit.with_record_value(456).is_unit());
+    assert_eq!(Unit.set_record_text("456".into()), None);
+}
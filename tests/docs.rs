@@ -0,0 +1,40 @@
+#![deny(missing_docs)]
+//! Validate that every derived method is documented.
+
+/// Documented enums remain fully documented once derived.
+pub mod colors {
+    use variantly::Variantly;
+
+    /// A color.
+    #[derive(Variantly, Debug, PartialEq)]
+    pub enum Color {
+        /// Red, green & blue.
+        Rgb(u8, u8, u8),
+        /// A custom color.
+        #[variantly(payload_struct)]
+        Custom {
+            /// Name of the color.
+            name: String,
+            /// Opacity of the color.
+            alpha: u8,
+        },
+        /// An unknown color.
+        Unknown,
+    }
+}
+
+use colors::Color;
+
+#[test]
+fn documented() {
+    assert!(Color::Rgb(1, 2, 3).is_rgb());
+    assert!(Color::Unknown.is_unknown());
+    assert_eq!(
+        Color::Custom {
+            name: "teal".into(),
+            alpha: 128,
+        }
+        .custom_alpha_ref(),
+        Some(&128)
+    );
+}
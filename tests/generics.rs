@@ -0,0 +1,118 @@
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+use variantly::Variantly;
+
+#[derive(Variantly, Debug, PartialEq)]
+enum Buffer<const N: usize> {
+    Full([u8; N]),
+    Partial { bytes: [u8; N], len: usize },
+    Empty,
+}
+
+#[derive(Variantly, Debug, PartialEq)]
+enum Defaulted<T: Clone = u8, const N: usize = 2> {
+    Single(T),
+    Many([T; N]),
+}
+
+#[derive(Variantly, Debug, PartialEq)]
+enum Bounded<'a, 'b: 'a, T: Clone + Debug + 'a>
+where
+    T: PartialEq,
+{
+    Borrowed(&'a T),
+    Label(&'b str),
+}
+
+#[derive(Variantly, Debug, PartialEq)]
+enum Unsized<'a, T: ?Sized> {
+    Borrowed(&'a T),
+    Nothing,
+}
+
+/// Enum type parameters share the names of the generic parameters of derived methods.
+#[derive(Variantly, Debug, PartialEq)]
+enum Outcome<E, F> {
+    Failed(E),
+    Fatal(F),
+    Passed,
+}
+
+/// Field types share the names of the generic parameters of derived methods.
+mod named_types {
+    #[derive(Debug, PartialEq)]
+    pub struct E(pub u8);
+    #[derive(Debug, PartialEq)]
+    pub struct F(pub u8);
+
+    #[derive(variantly::Variantly, Debug, PartialEq)]
+    pub enum Foo {
+        A(E),
+        B(Vec<F>),
+        C,
+    }
+}
+
+#[test]
+fn const_generics() {
+    let buffer: Buffer<3> = Buffer::Full([1, 2, 3]);
+    assert_eq!(buffer.full_ref(), Some(&[1, 2, 3]));
+    assert_eq!(buffer.unwrap_full(), [1, 2, 3]);
+
+    let buffer: Buffer<2> = Buffer::Partial {
+        bytes: [1, 0],
+        len: 1,
+    };
+    assert_eq!(buffer.partial_len_ref(), Some(&1));
+    assert!(Buffer::<2>::Empty.is_empty());
+}
+
+#[test]
+fn defaults() {
+    let defaulted: Defaulted = Defaulted::Many([1, 2]);
+    assert_eq!(defaulted.many_ref(), Some(&[1, 2]));
+
+    let defaulted: Defaulted<String, 1> = Defaulted::Single("one".into());
+    assert_eq!(defaulted.single(), Some("one".into()));
+}
+
+#[test]
+fn inline_bounds() {
+    let value = 5;
+    let bounded: Bounded<u8> = Bounded::Borrowed(&value);
+    assert_eq!(bounded.borrowed_ref(), Some(&&5));
+    assert_eq!(Bounded::<u8>::Label("label").unwrap_label(), "label");
+}
+
+#[test]
+fn unsized_params() {
+    let text: Unsized<str> = Unsized::Borrowed("text");
+    assert!(text.is_borrowed());
+    assert_eq!(text.unwrap_borrowed(), "text");
+    assert!(Unsized::<[u8]>::Nothing.is_nothing());
+}
+
+#[test]
+fn colliding_method_generics() {
+    let outcome: Outcome<&str, u8> = Outcome::Failed("failed");
+    assert_eq!(outcome.failed_or(1.5), Ok("failed"));
+
+    let outcome: Outcome<&str, u8> = Outcome::Fatal(1);
+    assert_eq!(outcome.failed_or_else(|| "not failed"), Err("not failed"));
+
+    let mut outcome: Outcome<&str, u8> = Outcome::Passed;
+    assert_eq!(outcome.fatal_mut_or("ERR"), Err("ERR"));
+    assert_eq!(outcome.passed_or(0), Ok(()));
+}
+
+#[test]
+fn colliding_field_types() {
+    use named_types::{Foo, E, F};
+
+    assert_eq!(Foo::A(E(1)).a_or("ERR"), Ok(E(1)));
+    assert_eq!(Foo::C.a_or_else(|| 2), Err(2));
+    assert_eq!(Foo::B(vec![F(1)]).b_ref_or(()), Ok(&vec![F(1)]));
+    assert_eq!(Foo::C.c_or_else(|| "ERR"), Ok(()));
+    assert_eq!(Foo::A(E(1)).or_else_b(|| vec![F(2)]), Foo::B(vec![F(2)]));
+}
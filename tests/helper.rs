@@ -25,10 +25,18 @@ pub enum TestEnum {
     Int(u128),
     Tuple(String, u128),
     StructLike { value: u128 },
+    Record { text: String, value: u128 },
 }
 
 impl TestEnum {
     pub fn new_tuple(num: u128) -> Self {
         Self::Tuple(num.to_string(), num)
     }
+
+    pub fn new_record(num: u128) -> Self {
+        Self::Record {
+            text: num.to_string(),
+            value: num,
+        }
+    }
 }
@@ -0,0 +1,48 @@
+#![allow(dead_code)]
+
+use variantly::Variantly;
+
+/// Field names match the names of parameters of derived methods, which must not be shadowed by the bindings of fields.
+#[derive(Variantly, Debug, PartialEq)]
+enum Shadowing {
+    Named {
+        or: u8,
+        or_else: u8,
+        value: u8,
+        msg: &'static str,
+    },
+    Tuple(u8, u8),
+    Empty,
+}
+
+fn named() -> Shadowing {
+    Shadowing::Named {
+        or: 1,
+        or_else: 2,
+        value: 3,
+        msg: "msg",
+    }
+}
+
+#[test]
+fn named_fields() {
+    assert_eq!(named().named_or("ERR"), Ok((1, 2, 3, "msg")));
+    assert_eq!(Shadowing::Empty.named_or_else(|| "ERR"), Err("ERR"));
+    assert_eq!(named().unwrap_or_named((0, 0, 0, "")), (1, 2, 3, "msg"));
+    assert_eq!(named().expect_named("msg"), (1, 2, 3, "msg"));
+
+    let mut shadowing = named();
+    assert_eq!(shadowing.set_named_value(5), Some(3));
+    assert_eq!(shadowing.named_value_ref(), Some(&5));
+    assert_eq!(named().with_named_or(7).named_or_ref(), Some(&7));
+}
+
+#[test]
+fn tuple_fields() {
+    let tuple = Shadowing::Tuple(1, 2).and_then_tuple(|(a, b)| (b, a));
+    assert_eq!(tuple, Shadowing::Tuple(2, 1));
+    assert_eq!(
+        Shadowing::Empty.or_else_tuple(|| (3, 4)),
+        Shadowing::Tuple(3, 4)
+    );
+}
@@ -0,0 +1,68 @@
+#![allow(dead_code, non_camel_case_types)]
+
+use variantly::Variantly;
+
+#[derive(Variantly, Debug, PartialEq)]
+enum Token {
+    Match(u8),
+    Type { name: &'static str },
+    Loop,
+    r#Struct(u8),
+    Gen(u8),
+    Async(u8),
+    Await(u8),
+    Dyn(u8),
+    Try(u8),
+}
+
+#[derive(Variantly, Debug, PartialEq)]
+#[variantly(rename_all = "verbatim")]
+enum Verbatim {
+    r#fn(u8),
+    #[variantly(rename = "r#type")]
+    Kind(u8),
+}
+
+#[derive(Variantly, Debug, PartialEq)]
+enum Unicode {
+    Déjà(u8),
+    ÜberGroß,
+    日本(u8),
+}
+
+#[test]
+fn keywords() {
+    assert_eq!(Token::Match(1).r#match(), Some(1));
+    assert!(Token::Match(1).is_match());
+    assert_eq!(Token::Type { name: "u8" }.r#type(), Some("u8"));
+    assert_eq!(Token::Type { name: "u8" }.type_name_ref(), Some(&"u8"));
+    assert!(Token::Loop.is_loop());
+    assert_eq!(Token::Loop.loop_or("ERR"), Ok(()));
+    assert_eq!(Token::r#Struct(2).r#struct(), Some(2));
+    assert_eq!(Token::Gen(3).r#gen(), Some(3));
+    assert_eq!(Token::Async(4).r#async(), Some(4));
+    assert_eq!(Token::Await(5).r#await(), Some(5));
+    assert_eq!(Token::Dyn(6).r#dyn(), Some(6));
+    assert_eq!(Token::Try(7).r#try(), Some(7));
+    assert!(Token::Try(7).is_try());
+}
+
+#[test]
+fn raw_identifiers() {
+    assert_eq!(Verbatim::r#fn(1).r#fn(), Some(1));
+    assert_eq!(Verbatim::r#fn(1).unwrap_fn(), 1);
+    assert_eq!(Verbatim::Kind(2).r#type(), Some(2));
+}
+
+#[test]
+#[should_panic(expected = "called `Token::unwrap_struct()` on a `Token::Loop` value")]
+fn raw_identifier_panic_message() {
+    Token::Loop.unwrap_struct();
+}
+
+#[test]
+fn non_ascii() {
+    assert_eq!(Unicode::Déjà(1).déjà(), Some(1));
+    assert!(Unicode::ÜberGroß.is_über_groß());
+    assert_eq!(Unicode::日本(2).unwrap_日本(), 2);
+}
@@ -0,0 +1,35 @@
+use variantly::Variantly;
+
+#[derive(Variantly, Debug, PartialEq)]
+#[variantly(naming(unwrap = "into_{}_unchecked", ref = "as_{}", field_ref = "{}"))]
+enum Templated {
+    Int(u128),
+    Named { value: u128 },
+    Unit,
+}
+
+#[derive(Variantly, Debug, PartialEq)]
+#[variantly(naming(prefix = "variant_", suffix = "_fn", is = "check_{}"))]
+enum Affixed {
+    Int(u128),
+    Unit,
+}
+
+#[test]
+fn templates() {
+    assert_eq!(Templated::Int(123).into_int_unchecked(), 123);
+    assert_eq!(Templated::Int(123).as_int(), Some(&123));
+    assert_eq!(Templated::Named { value: 123 }.named_value(), Some(&123));
+    Templated::Unit.into_unit_unchecked();
+
+    // Families without a template are named as usual.
+    assert!(Templated::Int(123).is_int());
+    assert_eq!(Templated::Int(123).int_mut(), Some(&mut 123));
+}
+
+#[test]
+fn affixes() {
+    assert_eq!(Affixed::Int(123).variant_unwrap_int_fn(), 123);
+    assert!(Affixed::Int(123).variant_check_int_fn());
+    assert!(Affixed::Unit.variant_is_not_int_fn());
+}
@@ -0,0 +1,31 @@
+//! Test compilation of the derive within a crate that does not link to `std`.
+#![no_std]
+
+use variantly::Variantly;
+
+#[derive(Variantly, Debug, PartialEq)]
+enum Sensor {
+    Temperature(i16),
+    Reading { channel: u8, value: u16 },
+    Offline,
+}
+
+#[test]
+fn derived_methods() {
+    let mut reading = Sensor::Reading {
+        channel: 1,
+        value: 512,
+    };
+    assert!(Sensor::Temperature(20).is_temperature());
+    assert_eq!(Sensor::Temperature(20).temperature_or(()), Ok(20));
+    assert_eq!(reading.reading_value_ref(), Some(&512));
+    assert_eq!(reading.set_reading_channel(2), Some(1));
+    assert_eq!(reading.unwrap_reading(), (2, 512));
+    assert_eq!(Sensor::Offline.offline_or_else(|| ()), Ok(()));
+}
+
+#[test]
+#[should_panic(expected = "called `Sensor::unwrap_temperature()` on a `Sensor::Offline` value")]
+fn panics_without_std() {
+    Sensor::Offline.unwrap_temperature();
+}
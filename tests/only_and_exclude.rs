@@ -0,0 +1,100 @@
+#![allow(dead_code)]
+
+use variantly::Variantly;
+
+#[derive(Variantly, Debug, PartialEq)]
+#[variantly(exclude(unwrap, expect))]
+enum Color {
+    Rgb(u8, u8, u8),
+    #[variantly(only(is, ref, mut))]
+    Grey(u8),
+    #[variantly(exclude(is_not))]
+    Hsl {
+        hue: u8,
+        saturation: u8,
+        lightness: u8,
+    },
+}
+
+#[derive(Variantly, Debug, PartialEq)]
+#[variantly(only(is, value_or))]
+enum Shape {
+    Circle(u32),
+    Unknown,
+}
+
+/// Implemented for every method that must not be derived. Calls resolve to these only if no inherent method exists.
+trait Excluded {
+    fn unwrap_rgb(self) -> bool;
+    fn expect_rgb(self, msg: &str) -> bool;
+    fn grey(self) -> bool;
+    fn unwrap_or_grey(self, or: u8) -> bool;
+    fn is_not_hsl(&self) -> bool;
+    fn circle_ref(&self) -> bool;
+    fn expect_unknown(self, msg: &str) -> bool;
+}
+
+impl<T> Excluded for T {
+    fn unwrap_rgb(self) -> bool {
+        false
+    }
+    fn expect_rgb(self, _: &str) -> bool {
+        false
+    }
+    fn grey(self) -> bool {
+        false
+    }
+    fn unwrap_or_grey(self, _: u8) -> bool {
+        false
+    }
+    fn is_not_hsl(&self) -> bool {
+        false
+    }
+    fn circle_ref(&self) -> bool {
+        false
+    }
+    fn expect_unknown(self, _: &str) -> bool {
+        false
+    }
+}
+
+#[test]
+fn enum_level_exclude() {
+    let color = Color::Rgb(1, 2, 3);
+    assert_eq!(color.rgb_ref(), Some((&1, &2, &3)));
+    assert!(!Color::Rgb(1, 2, 3).unwrap_rgb());
+    assert!(!Color::Rgb(1, 2, 3).expect_rgb("excluded"));
+}
+
+#[test]
+fn variant_level_only() {
+    let mut color = Color::Grey(128);
+    assert!(color.is_grey());
+    assert_eq!(color.grey_ref(), Some(&128));
+    *color.grey_mut().unwrap() = 0;
+    assert_eq!(color.grey_ref(), Some(&0));
+
+    assert!(!Color::Grey(128).grey());
+    assert!(!Color::Grey(128).unwrap_or_grey(0));
+}
+
+#[test]
+fn variant_level_exclude() {
+    let color = Color::Hsl {
+        hue: 1,
+        saturation: 2,
+        lightness: 3,
+    };
+    assert!(color.is_hsl());
+    assert!(!color.is_not_hsl());
+    assert_eq!(color.hsl_hue_ref(), Some(&1));
+}
+
+#[test]
+fn enum_level_only() {
+    assert!(Shape::Circle(1).is_circle());
+    assert_eq!(Shape::Circle(1).circle_or("ERR"), Ok(1));
+    assert_eq!(Shape::Unknown.unknown_or("ERR"), Ok(()));
+    assert!(!Shape::Circle(1).circle_ref());
+    assert!(!Shape::Unknown.expect_unknown("excluded"));
+}
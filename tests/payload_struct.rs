@@ -0,0 +1,201 @@
+#![allow(dead_code)]
+
+use variantly::Variantly;
+
+#[derive(Variantly, Debug, PartialEq)]
+enum Color {
+    Grey(u8),
+    #[variantly(payload_struct)]
+    Custom {
+        name: String,
+        alpha: u8,
+    },
+    #[variantly(payload_struct = "Mix")]
+    Mixed {
+        first: u8,
+        second: u8,
+    },
+}
+
+fn custom() -> Color {
+    Color::Custom {
+        name: "teal".into(),
+        alpha: 128,
+    }
+}
+
+/// Validate that payload structs only declare the generics used by their fields.
+#[derive(Variantly)]
+enum Generic<'a, 'b, A, B>
+where
+    A: Clone,
+    B: Default,
+{
+    #[variantly(payload_struct)]
+    OnlyA { value: &'a A, values: Vec<A> },
+    #[variantly(payload_struct)]
+    OnlyB { value: &'b B },
+}
+
+/// Validate that payload structs declare const parameters & defaults.
+#[derive(Variantly)]
+enum Fixed<T: Clone = u8, const N: usize = 4> {
+    #[variantly(payload_struct)]
+    Array { values: [T; N] },
+    #[variantly(payload_struct)]
+    Single { value: T },
+}
+
+/// Validate that `Self` within payload fields refers to the enum rather than the payload struct.
+#[derive(Variantly, Debug, PartialEq)]
+enum List<T> {
+    #[variantly(payload_struct)]
+    Cons {
+        value: T,
+        next: Box<Self>,
+    },
+    Nil,
+}
+
+#[test]
+fn by_value() {
+    // Match
+    let ColorCustomPayload { name, alpha } = custom().unwrap_custom();
+    assert_eq!((name.as_str(), alpha), ("teal", 128));
+    assert_eq!(custom().custom().map(|payload| payload.alpha), Some(128));
+    assert_eq!(
+        custom().custom_or("ERR").map(|payload| payload.name),
+        Ok("teal".into())
+    );
+
+    // Non-Match
+    assert!(Color::Grey(10).custom().is_none());
+    assert_eq!(Color::Grey(10).custom_or("ERR").err(), Some("ERR"));
+    let fallback = ColorCustomPayload {
+        name: "black".into(),
+        alpha: 0,
+    };
+    assert_eq!(Color::Grey(10).unwrap_or_custom(fallback).name, "black");
+}
+
+#[test]
+fn by_ref() {
+    // Match
+    let color = custom();
+    let ColorCustomPayloadRef { name, alpha } = color.custom_ref().unwrap();
+    assert_eq!((name, alpha), (&String::from("teal"), &128));
+
+    // Non-Match
+    assert!(Color::Grey(10).custom_ref().is_none());
+}
+
+#[test]
+fn by_mut() {
+    let mut color = custom();
+    let payload = color.custom_mut().unwrap();
+    *payload.alpha = 255;
+    payload.name.push_str("-ish");
+    assert_eq!(
+        color,
+        Color::Custom {
+            name: "teal-ish".into(),
+            alpha: 255
+        }
+    );
+
+    // Non-Match
+    assert!(Color::Grey(10).custom_mut().is_none());
+}
+
+#[test]
+fn and_then() {
+    let color = custom().and_then_custom(|payload| ColorCustomPayload {
+        alpha: 0,
+        ..payload
+    });
+    assert_eq!(
+        color,
+        Color::Custom {
+            name: "teal".into(),
+            alpha: 0
+        }
+    );
+}
+
+#[test]
+fn renamed() {
+    let color = Color::Mixed {
+        first: 1,
+        second: 2,
+    };
+    let MixRef { first, second } = color.mixed_ref().unwrap();
+    assert_eq!((first, second), (&1, &2));
+
+    let Mix { first, second } = color.unwrap_mixed();
+    assert_eq!((first, second), (1, 2));
+}
+
+#[test]
+fn from_payload() {
+    let color: Color = ColorCustomPayload {
+        name: "teal".into(),
+        alpha: 128,
+    }
+    .into();
+    assert_eq!(color, custom());
+
+    let color = Color::from(Mix {
+        first: 1,
+        second: 2,
+    });
+    assert!(color.is_mixed());
+}
+
+#[test]
+fn generic() {
+    let value = String::from("value");
+    let generic: Generic<String, u8> = Generic::OnlyA {
+        value: &value,
+        values: vec![value.clone(), value.clone()],
+    };
+    let GenericOnlyAPayloadRef { value, values } = generic.only_a_ref().unwrap();
+    assert_eq!(*value, "value");
+    assert_eq!(values.len(), 2);
+
+    let generic: Generic<String, u8> = GenericOnlyBPayload { value: &5 }.into();
+    assert_eq!(generic.unwrap_only_b().value, &5);
+}
+
+#[test]
+fn const_generic() {
+    let fixed: Fixed = FixedArrayPayload {
+        values: [1, 2, 3, 4],
+    }
+    .into();
+    let FixedArrayPayloadRef { values } = fixed.array_ref().unwrap();
+    assert_eq!(values, &[1, 2, 3, 4]);
+
+    let fixed: Fixed<char, 1> = FixedSinglePayload { value: 'a' }.into();
+    assert_eq!(fixed.unwrap_single().value, 'a');
+}
+
+#[test]
+fn recursive() {
+    let list = List::Cons {
+        value: 1,
+        next: Box::new(List::Cons {
+            value: 2,
+            next: Box::new(List::Nil),
+        }),
+    };
+    let ListConsPayload { value, next } = list.unwrap_cons();
+    assert_eq!(value, 1);
+    assert_eq!(next.cons_ref().map(|payload| *payload.value), Some(2));
+
+    let list: List<u8> = ListConsPayload {
+        value: 3,
+        next: Box::new(List::Nil),
+    }
+    .into();
+    assert!(list.is_cons());
+}
@@ -0,0 +1,48 @@
+#![allow(dead_code, non_camel_case_types)]
+
+use variantly::Variantly;
+
+#[derive(Variantly)]
+#[variantly(acronyms("IPv4", "IPv6", "HTTP"))]
+enum Address {
+    IPv4Addr(u32),
+    IPv6Addr(u128),
+    HTTPStatus(u16),
+    #[variantly(rename = "LocalIPv4")]
+    Localhost,
+}
+
+#[derive(Variantly)]
+#[variantly(rename_all = "verbatim")]
+enum Verbatim {
+    already_snake(u8),
+    Grey(u8),
+    #[variantly(rename = "renamed")]
+    Other,
+}
+
+#[derive(Variantly)]
+#[variantly(rename_all = "snake_case")]
+enum SnakeCase {
+    IPv4Addr(u32),
+}
+
+#[test]
+fn acronyms() {
+    assert_eq!(Address::IPv4Addr(1).ipv4_addr(), Some(1));
+    assert_eq!(Address::IPv6Addr(2).unwrap_ipv6_addr(), 2);
+    assert!(Address::HTTPStatus(404).is_http_status());
+    assert!(Address::Localhost.is_local_ipv4());
+}
+
+#[test]
+fn verbatim() {
+    assert_eq!(Verbatim::already_snake(1).already_snake(), Some(1));
+    assert!(Verbatim::Other.is_renamed());
+    assert!(Verbatim::Grey(1).is_Grey());
+}
+
+#[test]
+fn snake_case() {
+    assert_eq!(SnakeCase::IPv4Addr(1).i_pv_4_addr(), Some(1));
+}
@@ -0,0 +1,46 @@
+#![deny(deprecated)]
+#![allow(dead_code)]
+
+use variantly::Variantly;
+
+#[derive(Variantly, Debug, Clone, PartialEq)]
+#[variantly(allow_shadowing)]
+enum Conversion {
+    Into(u8),
+    Default,
+}
+
+#[derive(Variantly, Debug, Clone, PartialEq)]
+enum Operation {
+    #[variantly(allow_shadowing)]
+    Map(u8),
+    #[variantly(rename = "cloned_value")]
+    Clone(u8),
+}
+
+/// `Drop::drop` can not be called directly, so can not be shadowed.
+#[derive(Variantly, Debug, Clone, PartialEq)]
+enum Resource {
+    Drop,
+    Open(u8),
+}
+
+#[test]
+fn enum_level_opt_out() {
+    // The derived method takes precedence over `Into::into`.
+    assert_eq!(Conversion::Into(1).into(), Some(1));
+    assert!(Conversion::Default.is_default());
+}
+
+#[test]
+fn variant_level_opt_out() {
+    assert_eq!(Operation::Map(1).map(), Some(1));
+    assert_eq!(Operation::Clone(2).clone(), Operation::Clone(2));
+    assert_eq!(Operation::Clone(2).cloned_value(), Some(2));
+}
+
+#[test]
+fn unshadowable() {
+    assert!(Resource::Drop.is_drop());
+    assert_eq!(Resource::Open(1).drop_or("ERR"), Err("ERR"));
+}
@@ -0,0 +1,56 @@
+#![allow(dead_code)]
+
+use variantly::Variantly;
+
+/// Panic messages name the variant found without requiring `Debug`.
+#[derive(Variantly)]
+enum Color {
+    Rgb(u8, u8, u8),
+    #[variantly(skip)]
+    #[doc(hidden)]
+    Internal(String),
+    // Would collide with `Rgb` if not skipped.
+    #[variantly(skip, rename = "rgb")]
+    LegacyRgb(u8, u8, u8),
+    #[variantly(skip)]
+    Unknown,
+}
+
+/// Skipped variants may not implement the method generated for them.
+trait Generated {
+    fn is_internal(&self) -> bool {
+        false
+    }
+    fn internal(self) -> Option<String>;
+    fn is_unknown(&self) -> bool {
+        false
+    }
+}
+
+impl Generated for Color {
+    fn internal(self) -> Option<String> {
+        None
+    }
+}
+
+#[test]
+fn skipped_variants_have_no_methods() {
+    // The trait methods are only resolved if no inherent methods were derived for skipped variants.
+    assert!(!Color::Internal("private".into()).is_internal());
+    assert!(Color::Internal("private".into()).internal().is_none());
+    assert!(!Color::Unknown.is_unknown());
+}
+
+#[test]
+fn other_variants_have_methods() {
+    assert!(Color::Rgb(1, 2, 3).is_rgb());
+    assert!(Color::LegacyRgb(1, 2, 3).is_not_rgb());
+    assert_eq!(Color::Rgb(1, 2, 3).rgb(), Some((1, 2, 3)));
+    assert!(Color::Unknown.rgb().is_none());
+}
+
+#[test]
+#[should_panic(expected = "called `Color::unwrap_rgb()` on a `Color::Internal` value")]
+fn skipped_variant_panic_message() {
+    Color::Internal("private".into()).unwrap_rgb();
+}
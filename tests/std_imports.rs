@@ -27,7 +27,6 @@ enum TestPreludeImportConflict {
 
 #[no_implicit_prelude]
 mod no_std {
-    use ::std;
     use ::variantly::Variantly;
 
     // Test compilation w/o access to prelude contents.
@@ -0,0 +1,47 @@
+// Intentionally free of `#![allow(dead_code)]`, as methods of private enums should not be linted against.
+use variantly::Variantly;
+
+mod colors {
+    use variantly::Variantly;
+
+    #[derive(Variantly, Debug, PartialEq)]
+    #[variantly(vis = "pub(crate)")]
+    pub enum Color {
+        Rgb(u8, u8, u8),
+        #[variantly(vis = "")]
+        Grey(u8),
+        #[variantly(vis = "pub")]
+        Hsl(u8, u8, u8),
+    }
+
+    /// Private methods remain accessible within the defining module.
+    pub fn grey_ref(color: &Color) -> Option<&u8> {
+        color.grey_ref()
+    }
+}
+
+use colors::Color;
+
+#[derive(Variantly)]
+enum Private {
+    Used(u8),
+    Unit,
+}
+
+#[test]
+fn enum_level_vis() {
+    assert!(Color::Rgb(1, 2, 3).is_rgb());
+    assert_eq!(Color::Rgb(1, 2, 3).rgb(), Some((1, 2, 3)));
+}
+
+#[test]
+fn variant_level_vis() {
+    assert_eq!(Color::Hsl(1, 2, 3).hsl_ref(), Some((&1, &2, &3)));
+    assert_eq!(colors::grey_ref(&Color::Grey(128)), Some(&128));
+}
+
+#[test]
+fn private_enum() {
+    assert!(Private::Used(1).is_used());
+    assert!(Private::Unit.is_unit());
+}